@@ -0,0 +1,36 @@
+use crate::{components::drip_vault_yield, states::Vault, utils::SCALE};
+
+/// Credits an LP fee to the vault's cumulative yield per unit of LP weight.
+///
+/// LP weight is the LP supply plus the extra weight of boosted (time-locked) positions,
+/// so locked stakers earn their boosted share. With a `yield_drip_window` configured the
+/// fee is buffered and the whole buffer is dripped into the accumulator over the next
+/// window instead.
+/// Without any LP the fee simply stays in the vault.
+///
+/// Credited fees are tracked in `unclaimed_yield` until stakers claim or compound them.
+///
+/// # Arguments
+/// * `vault` - The vault earning the fee
+/// * `lp_fee` - The fee amount owed to the vault's stakers
+/// * `current_timestamp` - The current unix timestamp
+pub fn accrue_vault_yield(vault: &mut Vault, lp_fee: u64, current_timestamp: i64) {
+    // Release what has vested so far before adding to the buffer
+    drip_vault_yield(vault, current_timestamp);
+
+    if vault.yield_drip_window > 0 {
+        vault.undripped_yield += lp_fee;
+        vault.last_drip_time = current_timestamp;
+        vault.drip_end_time = current_timestamp + vault.yield_drip_window;
+        return;
+    }
+
+    let total_weight: u128 = vault.initial_liquidity as u128 + vault.total_boost_weight as u128;
+
+    if total_weight == 0 {
+        return;
+    }
+
+    vault.cumulative_yield_per_lp += (lp_fee as u128 * SCALE) / total_weight;
+    vault.unclaimed_yield += lp_fee;
+}
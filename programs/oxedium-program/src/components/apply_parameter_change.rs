@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{components::drip_vault_yield, events::{TreasuryUpdated, VaultUpdated}, instructions::admin::DepositCaps, states::{Treasury, Vault, VaultParams}};
+
+/// Applies protocol fee and deviation to the treasury.
+///
+/// # Arguments
+/// * `treasury` - The treasury being updated
+/// * `fee_bps` - Protocol fee in basis points
+/// * `deviation` - Liquidity threshold divisor (validated by the caller)
+pub fn apply_treasury_params(treasury: &mut Treasury, fee_bps: u64, deviation: u64) {
+    let old_fee_bps = treasury.fee_bps;
+    let old_deviation = treasury.deviation;
+
+    treasury.fee_bps = fee_bps;     // protocol fee in bps
+    treasury.deviation = deviation;
+
+    emit!(TreasuryUpdated {
+        old_fee_bps,
+        fee_bps,
+        old_deviation,
+        deviation
+    });
+}
+
+/// Applies fee, oracle and staking parameters to a vault.
+///
+/// Fees dripped under the old window are released first so the new window
+/// only applies to yield accrued from now on.
+///
+/// # Arguments
+/// * `vault` - The vault being updated
+/// * `params` - The new parameters (validated by the caller)
+/// * `now` - Current unix timestamp
+pub fn apply_vault_params(vault: &mut Vault, params: &VaultParams, now: i64) {
+    // Release fees dripped under the old window before switching to the new one
+    drip_vault_yield(vault, now);
+
+    let old = VaultParams {
+        base_fee: vault.base_fee,
+        pyth_price_account: vault.pyth_price_account,
+        max_age_price: vault.max_age_price,
+        min_stake_age: vault.min_stake_age,
+        yield_drip_window: vault.yield_drip_window,
+        deposit_caps: DepositCaps {
+            max_total_deposits: vault.max_total_deposits,
+            max_deposit_per_user: vault.max_deposit_per_user,
+            caps_in_usd: vault.caps_in_usd,
+        },
+    };
+
+    vault.base_fee = params.base_fee;                     // set the base fee for the vault
+    vault.pyth_price_account = params.pyth_price_account; // update Pyth price feed
+    vault.max_age_price = params.max_age_price;           // max allowed age of price feed
+    vault.min_stake_age = params.min_stake_age;           // yield vesting period for fresh deposits
+    vault.yield_drip_window = params.yield_drip_window;   // fee drip window
+    vault.max_total_deposits = params.deposit_caps.max_total_deposits;     // 0 = no vault cap
+    vault.max_deposit_per_user = params.deposit_caps.max_deposit_per_user; // 0 = no per-user cap
+    vault.caps_in_usd = params.deposit_caps.caps_in_usd;                   // caps valued via the oracle
+
+    emit!(VaultUpdated {
+        mint: vault.token_mint,
+        old,
+        new: *params
+    });
+}
@@ -0,0 +1,19 @@
+use crate::{components::{accrue_vault_yield, SwapMathResult}, states::Vault};
+
+/// Books a swap on both vaults.
+///
+/// The input vault's liquidity grows by the tokens swapped in, the output vault pays out
+/// the net amount and earns the LP and protocol fees.
+///
+/// # Arguments
+/// * `vault_in` - The vault receiving `amount_in`
+/// * `vault_out` - The vault paying out `result.net_amount_out`
+/// * `amount_in` - The amount of input tokens now held for `vault_in`
+/// * `result` - The swap math of the trade
+/// * `current_timestamp` - The current unix timestamp
+pub fn apply_swap_result(vault_in: &mut Vault, vault_out: &mut Vault, amount_in: u64, result: &SwapMathResult, current_timestamp: i64) {
+    vault_in.current_liquidity += amount_in;
+    vault_out.current_liquidity -= result.net_amount_out;
+    accrue_vault_yield(vault_out, result.lp_fee_amount, current_timestamp);
+    vault_out.protocol_yield += result.protocol_fee_amount;
+}
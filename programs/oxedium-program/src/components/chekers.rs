@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{states::Treasury, utils::OxediumError};
+use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, PriceUpdateV2};
+use crate::{components::{allowlist_leaf, deposit_value, verify_merkle_proof}, instructions::admin::ParameterBounds, states::{FeeRecipient, Role, Staker, Treasury, Vault, VaultParams, VaultStatus}, utils::{OxediumError, MAX_FEE_RECIPIENTS, MAX_MULTISIG_SIGNERS}};
 
 /// Checks if the given signer is the admin of the treasury.
 /// Returns `InvalidAdmin` error if not.
@@ -11,6 +12,149 @@ pub fn check_admin(treasury_pda: &Treasury, signer: &Signer) -> Result<()> {
     Ok(())
 }
 
+/// Checks if the given signer holds `role` or is the admin of the treasury.
+/// Returns `MissingRole` error if not.
+pub fn check_role(treasury_pda: &Treasury, role: Role, signer: &Pubkey) -> Result<()> {
+    let holder = match role {
+        Role::Pauser => treasury_pda.pauser,
+        Role::FeeManager => treasury_pda.fee_manager,
+        Role::VaultLister => treasury_pda.vault_lister,
+        Role::FeeCollector => treasury_pda.fee_collector,
+        Role::Guardian => treasury_pda.guardian,
+    };
+
+    let has_role = holder != Pubkey::default() && *signer == holder;
+
+    if *signer != treasury_pda.admin && !has_role {
+        return Err(OxediumError::MissingRole.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that a multisig signer set is usable: 1..=`MAX_MULTISIG_SIGNERS` unique keys
+/// and a threshold between 1 and the number of signers.
+/// Returns `InvalidMultisigConfig` error otherwise.
+pub fn check_multisig_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    let mut unique = signers.to_vec();
+    unique.sort();
+    unique.dedup();
+
+    if signers.is_empty()
+        || signers.len() > MAX_MULTISIG_SIGNERS
+        || unique.len() != signers.len()
+        || threshold == 0
+        || threshold as usize > signers.len()
+    {
+        return Err(OxediumError::InvalidMultisigConfig.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that fee recipients are usable: 1..=`MAX_FEE_RECIPIENTS` non-default wallets
+/// whose weights sum to exactly 10_000 bps.
+/// Returns `InvalidFeeRecipients` error otherwise.
+pub fn check_fee_recipients(recipients: &[FeeRecipient]) -> Result<()> {
+    let total_bps: u128 = recipients.iter().map(|recipient| recipient.weight_bps as u128).sum();
+
+    if recipients.is_empty()
+        || recipients.len() > MAX_FEE_RECIPIENTS
+        || recipients.iter().any(|recipient| recipient.owner == Pubkey::default())
+        || total_bps != 10_000
+    {
+        return Err(OxediumError::InvalidFeeRecipients.into());
+    }
+
+    Ok(())
+}
+
+/// Checks if the given signer is the pending admin proposed via `propose_admin`.
+/// Returns `InvalidPendingAdmin` error if no handover is in progress or the signer differs.
+pub fn check_pending_admin(treasury_pda: &Treasury, signer: &Pubkey) -> Result<()> {
+    if treasury_pda.pending_admin == Pubkey::default() || *signer != treasury_pda.pending_admin {
+        return Err(OxediumError::InvalidPendingAdmin.into());
+    }
+
+    Ok(())
+}
+
+/// Checks if the given signer is the owner of the staker position or its delegate.
+/// Returns `InvalidStakerAuthority` error if neither.
+pub fn check_staker_authority(staker: &Staker, signer: &Pubkey) -> Result<()> {
+    let is_delegate = staker.delegate != Pubkey::default() && *signer == staker.delegate;
+
+    if *signer != staker.owner && !is_delegate {
+        return Err(OxediumError::InvalidStakerAuthority.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that parameters can be changed instantly, i.e. no timelock delay is configured.
+/// Returns `TimelockActive` error otherwise; changes must then go through `queue_change`.
+pub fn check_timelock_disabled(treasury_pda: &Treasury) -> Result<()> {
+    if treasury_pda.timelock_delay > 0 {
+        return Err(OxediumError::TimelockActive.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that vault parameters are well-formed and within the treasury bounds.
+/// Returns `InvalidDuration` error if the vesting period or drip window is negative,
+/// otherwise the errors of `check_vault_bounds`.
+pub fn check_vault_params(treasury_pda: &Treasury, params: &VaultParams) -> Result<()> {
+    if params.min_stake_age < 0 || params.yield_drip_window < 0 {
+        return Err(OxediumError::InvalidDuration.into());
+    }
+
+    check_vault_bounds(treasury_pda, params.base_fee, params.max_age_price)
+}
+
+/// Checks that parameter bounds are usable: fees at most 10_000 bps, a non-zero oracle
+/// age and a deviation divisor of at least 1.
+/// Returns `InvalidParameterBounds` error otherwise.
+pub fn check_parameter_bounds(bounds: &ParameterBounds) -> Result<()> {
+    if bounds.max_base_fee > 10_000
+        || bounds.max_protocol_fee_bps > 10_000
+        || bounds.max_oracle_age == 0
+        || bounds.min_deviation == 0
+    {
+        return Err(OxediumError::InvalidParameterBounds.into());
+    }
+
+    Ok(())
+}
+
+/// Checks a vault's fee and oracle settings against the treasury bounds.
+/// Returns `BaseFeeTooHigh` or `OracleAgeTooHigh` for the violated bound.
+pub fn check_vault_bounds(treasury_pda: &Treasury, base_fee: u64, max_age_price: u64) -> Result<()> {
+    if base_fee > treasury_pda.max_base_fee {
+        return Err(OxediumError::BaseFeeTooHigh.into());
+    }
+
+    if max_age_price > treasury_pda.max_oracle_age {
+        return Err(OxediumError::OracleAgeTooHigh.into());
+    }
+
+    Ok(())
+}
+
+/// Checks the treasury fee and deviation against the treasury bounds.
+/// Returns `ProtocolFeeTooHigh` or `DeviationTooLow` for the violated bound.
+pub fn check_treasury_params(treasury_pda: &Treasury, fee_bps: u64, deviation: u64) -> Result<()> {
+    if fee_bps > treasury_pda.max_protocol_fee_bps {
+        return Err(OxediumError::ProtocolFeeTooHigh.into());
+    }
+
+    if deviation < treasury_pda.min_deviation {
+        return Err(OxediumError::DeviationTooLow.into());
+    }
+
+    Ok(())
+}
+
 /// Checks if the vault and treasury are not under a "stoptap" condition.
 /// Returns `StoptapActivated` error if either the vault is inactive or the treasury stoptap is enabled.
 pub fn check_stoptap(treasury_pda: &Treasury) -> Result<()> {
@@ -19,4 +163,153 @@ pub fn check_stoptap(treasury_pda: &Treasury) -> Result<()> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Checks that the vault accepts new stakes and inbound swaps.
+/// Returns `VaultNotActive` error once the vault is being delisted.
+pub fn check_vault_inflow(vault: &Vault) -> Result<()> {
+    if vault.status != VaultStatus::Active {
+        return Err(OxediumError::VaultNotActive.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that a retired vault can be closed.
+/// Returns `InvalidVaultStatus` unless the vault is `WithdrawOnly`, and `VaultNotEmpty`
+/// while it has LP, uncollected protocol yield, buffered fees or open Staker accounts.
+/// Closed Staker accounts have been paid out, so whatever balance is left is rounding dust.
+pub fn check_vault_closable(vault: &Vault, lp_supply: u64) -> Result<()> {
+    if vault.status != VaultStatus::WithdrawOnly {
+        return Err(OxediumError::InvalidVaultStatus.into());
+    }
+
+    if vault.initial_liquidity != 0
+        || lp_supply != 0
+        || vault.protocol_yield != 0
+        || vault.undripped_yield != 0
+        || vault.open_stakers != 0
+    {
+        return Err(OxediumError::VaultNotEmpty.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that tokens may be swapped out of the vault.
+/// Returns `VaultWithdrawOnly` error once only LP withdrawals are allowed.
+pub fn check_vault_outflow(vault: &Vault) -> Result<()> {
+    if vault.status == VaultStatus::WithdrawOnly {
+        return Err(OxediumError::VaultWithdrawOnly.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that transferable NFT positions may be opened in the vault.
+/// Returns `PositionsNotAllowed` if the vault has a per-user deposit cap, which a
+/// position (that can change hands) would otherwise bypass.
+pub fn check_positions_allowed(vault: &Vault) -> Result<()> {
+    if vault.max_deposit_per_user > 0 {
+        return Err(OxediumError::PositionsNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that an instruction moving value between two vaults got two different ones.
+/// Returns `SameVault` otherwise: the same account loaded twice would have one copy's
+/// changes overwritten by the other's.
+pub fn check_distinct_vaults(vault_in: &Pubkey, vault_out: &Pubkey) -> Result<()> {
+    if vault_in == vault_out {
+        return Err(OxediumError::SameVault.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the Pyth account is the one registered for the vault and that its price is fresh.
+/// Returns `InvalidPythAccount` on a mismatch and `OracleDataTooOld` if the price is older than `max_age_price`.
+pub fn check_oracle(vault: &Vault, oracle: &Account<PriceUpdateV2>, current_timestamp: i64) -> Result<()> {
+    if oracle.key() != vault.pyth_price_account {
+        return Err(OxediumError::InvalidPythAccount.into());
+    }
+
+    let price_age = current_timestamp - oracle.price_message.publish_time;
+
+    if price_age > vault.max_age_price as i64 {
+        msg!("Vault {}: Price feed stale by {} seconds", vault.token_mint, price_age);
+        return Err(OxediumError::OracleDataTooOld.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the wallet may use a permissioned vault.
+/// Open vaults accept everyone; allowlisted vaults require a Merkle proof of the wallet
+/// against `allowlist_root`. Returns `NotAllowlisted` if the proof does not verify.
+pub fn check_allowlist(vault: &Vault, wallet: &Pubkey, proof: &[[u8; 32]]) -> Result<()> {
+    if !vault.allowlist_enabled {
+        return Ok(());
+    }
+
+    if !verify_merkle_proof(proof, vault.allowlist_root, allowlist_leaf(wallet)) {
+        msg!("Vault {}: wallet {} is not allowlisted", vault.token_mint, wallet);
+        return Err(OxediumError::NotAllowlisted.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that a deposit of `amount` tokens stays within the vault's deposit caps.
+/// A cap of zero means no limit. Caps are valued in USD when `caps_in_usd` is set, which
+/// requires the token's price message.
+/// Returns `VaultDepositCapExceeded` / `UserDepositCapExceeded` and logs the remaining capacity.
+///
+/// The per-user cap only sees what the caller passes as `user_deposits`, i.e. the LP in
+/// the depositor's ATA and lock. LP tokens are transferable, so a user can move LP to
+/// another wallet and deposit again; the per-user cap limits single deposits, not holdings.
+///
+/// # Arguments
+/// * `vault` - The vault receiving the deposit
+/// * `user_deposits` - The depositor's current position in the vault (liquid plus locked LP)
+/// * `amount` - Tokens being deposited
+/// * `decimals` - Decimals of the vault token
+/// * `price_message` - Pyth price of the vault token, required for USD caps
+pub fn check_deposit_caps(
+    vault: &Vault,
+    user_deposits: u64,
+    amount: u64,
+    decimals: u8,
+    price_message: Option<&PriceFeedMessage>,
+) -> Result<()> {
+    let price_message = if vault.caps_in_usd {
+        Some(price_message.ok_or(OxediumError::MissingPriceAccount)?)
+    } else {
+        None
+    };
+
+    if vault.max_total_deposits > 0 {
+        let total: u64 = deposit_value(vault.initial_liquidity, decimals, price_message)?;
+        let deposit: u64 = deposit_value(amount, decimals, price_message)?;
+        let remaining: u64 = vault.max_total_deposits.saturating_sub(total);
+
+        if deposit > remaining {
+            msg!("Vault {}: deposit cap exceeded, remaining capacity {}", vault.token_mint, remaining);
+            return Err(OxediumError::VaultDepositCapExceeded.into());
+        }
+    }
+
+    if vault.max_deposit_per_user > 0 {
+        let position: u64 = deposit_value(user_deposits, decimals, price_message)?;
+        let deposit: u64 = deposit_value(amount, decimals, price_message)?;
+        let remaining: u64 = vault.max_deposit_per_user.saturating_sub(position);
+
+        if deposit > remaining {
+            msg!("Vault {}: user deposit cap exceeded, remaining capacity {}", vault.token_mint, remaining);
+            return Err(OxediumError::UserDepositCapExceeded.into());
+        }
+    }
+
+    Ok(())
+}
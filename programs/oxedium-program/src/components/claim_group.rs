@@ -0,0 +1,38 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::utils::{OxediumError, STAKER_SEED, VAULT_SEED};
+
+/// Number of remaining accounts per claimed vault in `claim_many`
+pub const CLAIM_MANY_GROUP_SIZE: usize = 5;
+
+/// Returns the number of vault groups in `claim_many`'s remaining accounts.
+///
+/// Returns `InvalidRemainingAccounts` unless there is at least one group and
+/// the accounts split into whole groups.
+// `usize::is_multiple_of` is newer than the rustc shipped with the SBF platform tools
+#[allow(clippy::manual_is_multiple_of)]
+pub fn claim_group_count(remaining_len: usize) -> Result<usize, OxediumError> {
+    if remaining_len == 0 || remaining_len % CLAIM_MANY_GROUP_SIZE != 0 {
+        return Err(OxediumError::InvalidRemainingAccounts);
+    }
+
+    Ok(remaining_len / CLAIM_MANY_GROUP_SIZE)
+}
+
+/// Derives the accounts a `claim_many` group must contain for a vault token.
+///
+/// # Arguments
+/// * `signer` - The claiming staker
+/// * `token_mint` - The vault token mint
+/// * `lp_mint` - The vault's LP mint
+///
+/// # Returns
+/// * `(Pubkey, Pubkey, Pubkey)` - The vault PDA, the signer's staker PDA and the
+///   signer's LP associated token account
+pub fn claim_group_addresses(signer: &Pubkey, token_mint: &Pubkey, lp_mint: &Pubkey) -> (Pubkey, Pubkey, Pubkey) {
+    let (vault, _) = Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), token_mint.as_ref()], &crate::ID);
+    let (staker, _) = Pubkey::find_program_address(&[STAKER_SEED.as_bytes(), vault.as_ref(), signer.as_ref()], &crate::ID);
+    let lp_ata = get_associated_token_address(signer, lp_mint);
+
+    (vault, staker, lp_ata)
+}
@@ -0,0 +1,40 @@
+use pyth_solana_receiver_sdk::price_update::PriceFeedMessage;
+use crate::utils::{OxediumError, USD_DECIMALS};
+
+/// Converts a token amount into the unit the vault's deposit caps are expressed in.
+///
+/// Without a price message the amount is returned as is (token units). With a price
+/// message the amount is valued in USD with `USD_DECIMALS` decimals, using Pyth's
+/// `price * 10^exponent` convention (the exponent is usually negative).
+///
+/// # Arguments
+/// * `amount` - Token amount in the smallest units
+/// * `decimals` - Decimals of the token mint
+/// * `price_message` - Pyth price of the token, `None` for token-denominated caps
+pub fn deposit_value(amount: u64, decimals: u8, price_message: Option<&PriceFeedMessage>) -> Result<u64, OxediumError> {
+    let price_message = match price_message {
+        Some(price_message) => price_message,
+        None => return Ok(amount),
+    };
+
+    if price_message.price <= 0 {
+        return Err(OxediumError::OverflowInSub);
+    }
+
+    // A negative exponent divides the price, a positive one multiplies it
+    let (exp_mul, exp_div): (u32, u32) = if price_message.exponent < 0 {
+        (0, price_message.exponent.unsigned_abs())
+    } else {
+        (price_message.exponent as u32, 0)
+    };
+
+    let value = (amount as u128)
+        .checked_mul(price_message.price as u128)
+        .ok_or(OxediumError::OverflowInMul)?
+        .checked_mul(10u128.pow(USD_DECIMALS + exp_mul))
+        .ok_or(OxediumError::OverflowInMul)?
+        .checked_div(10u128.pow(exp_div + decimals as u32))
+        .ok_or(OxediumError::OverflowInDiv)?;
+
+    u64::try_from(value).map_err(|_| OxediumError::OverflowInCast)
+}
@@ -0,0 +1,43 @@
+use crate::{states::Vault, utils::SCALE};
+
+/// Releases the part of the vault's undripped LP fees that has vested since the last drip
+/// into the cumulative yield.
+///
+/// The buffer is released at a constant rate until `drip_end_time`, so a position opened
+/// right before a large swap only captures a small slice of its fee. Each new fee
+/// restarts the schedule over `yield_drip_window` seconds (see `accrue_vault_yield`).
+/// With a zero window, or once `drip_end_time` has passed, the whole buffer is released.
+///
+/// `last_drip_time` only advances when something is released, so frequent calls cannot
+/// strand the buffer through rounding.
+///
+/// # Arguments
+/// * `vault` - The vault whose buffered fees to release
+/// * `current_timestamp` - The current unix timestamp
+pub fn drip_vault_yield(vault: &mut Vault, current_timestamp: i64) {
+    if vault.undripped_yield == 0 {
+        vault.last_drip_time = current_timestamp;
+        return;
+    }
+
+    let elapsed: i64 = (current_timestamp - vault.last_drip_time).max(0);
+    let remaining: i64 = vault.drip_end_time - vault.last_drip_time;
+
+    let released: u64 = if vault.yield_drip_window <= 0 || elapsed >= remaining {
+        vault.undripped_yield
+    } else {
+        (vault.undripped_yield as u128 * elapsed as u128 / remaining as u128) as u64
+    };
+
+    let total_weight: u128 = vault.initial_liquidity as u128 + vault.total_boost_weight as u128;
+
+    // Keep buffering while there is nothing to release or nobody to pay
+    if released == 0 || total_weight == 0 {
+        return;
+    }
+
+    vault.last_drip_time = current_timestamp;
+    vault.undripped_yield -= released;
+    vault.cumulative_yield_per_lp += (released as u128 * SCALE) / total_weight;
+    vault.unclaimed_yield += released;
+}
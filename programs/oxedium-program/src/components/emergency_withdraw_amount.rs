@@ -0,0 +1,34 @@
+use crate::{states::Vault, utils::OxediumError};
+
+/// Computes the pro-rata share of the vault's remaining balance for `lp_amount` LP tokens.
+///
+/// The remaining balance is what the treasury token account holds for the vault minus
+/// everything already owed to others: uncollected protocol yield, buffered LP fees and
+/// yield credited to stakers but not yet claimed. No oracle prices or dynamic fees are
+/// involved, so the share can be computed while price feeds are unavailable.
+///
+/// # Arguments
+/// * `vault` - The vault being withdrawn from
+/// * `balance` - The treasury token account balance of the vault token
+/// * `lp_amount` - The LP tokens being burned
+///
+/// # Returns
+/// * `u64` - The amount of vault tokens owed to the LP
+pub fn emergency_withdraw_amount(vault: &Vault, balance: u64, lp_amount: u64) -> Result<u64, OxediumError> {
+    if vault.initial_liquidity == 0 {
+        return Ok(0);
+    }
+
+    let remaining: u128 = balance
+        .saturating_sub(vault.protocol_yield)
+        .saturating_sub(vault.undripped_yield)
+        .saturating_sub(vault.unclaimed_yield) as u128;
+
+    let amount = remaining
+        .checked_mul(lp_amount as u128)
+        .ok_or(OxediumError::OverflowInMul)?
+        .checked_div(vault.initial_liquidity as u128)
+        .ok_or(OxediumError::OverflowInDiv)?;
+
+    u64::try_from(amount).map_err(|_| OxediumError::OverflowInCast)
+}
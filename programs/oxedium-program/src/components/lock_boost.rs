@@ -0,0 +1,46 @@
+use crate::utils::OxediumError;
+
+/// Returns the yield boost (in basis points, 10_000 = 1x) for a lock duration.
+///
+/// Supported tiers:
+/// - 30 days  → 1.10x
+/// - 90 days  → 1.25x
+/// - 180 days → 1.50x
+///
+/// # Returns
+/// * `Result<u64, OxediumError>` - `InvalidLockDuration` for any other duration
+pub fn lock_boost_bps(lock_days: u16) -> Result<u64, OxediumError> {
+    match lock_days {
+        30 => Ok(11_000),
+        90 => Ok(12_500),
+        180 => Ok(15_000),
+        _ => Err(OxediumError::InvalidLockDuration),
+    }
+}
+
+/// Extra LP weight a locked amount adds on top of its face value.
+///
+/// # Example
+/// 1_000 LP locked at 12_500 bps → 250 extra weight
+pub fn boost_weight(locked_amount: u64, boost_bps: u64) -> u64 {
+    (locked_amount as u128 * boost_bps.saturating_sub(10_000) as u128 / 10_000) as u64
+}
+
+/// Part of the yield earned on a boost since the last settlement that falls before `lock_end`.
+///
+/// Yield accrues to the accumulator without timestamps, so it is split pro-rata by time
+/// over `[last_settle_time, now]`; the boost stops counting once the lock has expired.
+///
+/// # Example
+/// 100 boost yield over 10 days, lock expired after 4 of them → 40 kept
+pub fn unexpired_boost_yield(boost_yield: u64, last_settle_time: i64, lock_end: i64, now: i64) -> u64 {
+    let interval: i64 = now - last_settle_time;
+
+    if interval <= 0 {
+        return 0;
+    }
+
+    let boosted: i64 = (lock_end - last_settle_time).clamp(0, interval);
+
+    (boost_yield as u128 * boosted as u128 / interval as u128) as u64
+}
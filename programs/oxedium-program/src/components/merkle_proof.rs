@@ -0,0 +1,36 @@
+use anchor_lang::prelude::Pubkey;
+use solana_sha256_hasher::hashv;
+
+/// Computes the allowlist Merkle leaf of a wallet: `sha256(0x00 || wallet)`.
+///
+/// The leaf prefix differs from the node prefix so an inner node can never be
+/// presented as a leaf.
+pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0x00], wallet.as_ref()]).to_bytes()
+}
+
+/// Verifies a Merkle proof for `leaf` against `root`.
+///
+/// Inner nodes are `sha256(0x01 || min(a, b) || max(a, b))`, so sibling order does
+/// not need to be encoded in the proof.
+///
+/// # Arguments
+/// * `proof` - Sibling hashes from the leaf up to the root
+/// * `root` - The expected Merkle root
+/// * `leaf` - The leaf being proven
+///
+/// # Returns
+/// * `bool` - `true` if the proof resolves to `root`
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut node: [u8; 32] = leaf;
+
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&[0x01], &node[..], &sibling[..]]).to_bytes()
+        } else {
+            hashv(&[&[0x01], &sibling[..], &node[..]]).to_bytes()
+        };
+    }
+
+    node == root
+}
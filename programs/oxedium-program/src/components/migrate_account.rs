@@ -0,0 +1,120 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use crate::{states::{Staker, Treasury, Vault}, utils::*};
+
+/// Byte length of a treasury up to and including the parameter bounds.
+/// Shorter (older) treasuries never had bounds set.
+const TREASURY_BOUNDS_END: usize = 8 + 1 + 32 + 8 + 8 + 32 + 5 * 32 + 8 + 4 * 8;
+
+/// Deserializes an account stored with an older, shorter layout into the current one.
+///
+/// Fields were only ever appended, so the old bytes are a prefix of the new layout;
+/// missing fields are read as zero.
+///
+/// # Arguments
+/// * `data` - Raw account data, including the discriminator
+/// * `space` - Size of the current layout, including the discriminator
+///
+/// # Returns
+/// * `Result<T>` - The account in the current layout
+pub fn upgrade_account_data<T: AccountDeserialize + Discriminator>(data: &[u8], space: usize) -> Result<T> {
+    if !data.starts_with(T::DISCRIMINATOR) {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    if data.len() > space {
+        return Err(OxediumError::InvalidAccountLayout.into());
+    }
+
+    let mut padded = data.to_vec();
+    padded.resize(space, 0);
+
+    T::try_deserialize(&mut &padded[..])
+}
+
+/// Upgrades raw treasury data to `TREASURY_VERSION`.
+///
+/// Treasuries created before parameter bounds existed get the default bounds, since
+/// zero bounds would reject every fee update.
+pub fn upgrade_treasury(data: &[u8]) -> Result<Treasury> {
+    let mut treasury: Treasury = upgrade_account_data(data, 8 + Treasury::INIT_SPACE)?;
+
+    if treasury.version >= TREASURY_VERSION {
+        return Err(OxediumError::AccountAlreadyMigrated.into());
+    }
+
+    if data.len() < TREASURY_BOUNDS_END {
+        treasury.max_base_fee = DEFAULT_MAX_BASE_FEE;
+        treasury.max_protocol_fee_bps = DEFAULT_MAX_PROTOCOL_FEE_BPS;
+        treasury.max_oracle_age = DEFAULT_MAX_ORACLE_AGE;
+        treasury.min_deviation = DEFAULT_MIN_DEVIATION;
+    }
+
+    treasury.version = TREASURY_VERSION;
+
+    Ok(treasury)
+}
+
+/// Upgrades raw vault data to `VAULT_VERSION`.
+///
+/// Zero is the intended default of every appended vault field (no caps, no allowlist,
+/// no vesting or drip, `Active` status).
+pub fn upgrade_vault(data: &[u8]) -> Result<Vault> {
+    let mut vault: Vault = upgrade_account_data(data, 8 + Vault::INIT_SPACE)?;
+
+    if vault.version >= VAULT_VERSION {
+        return Err(OxediumError::AccountAlreadyMigrated.into());
+    }
+
+    vault.version = VAULT_VERSION;
+
+    Ok(vault)
+}
+
+/// Upgrades raw staker data to `STAKER_VERSION`.
+///
+/// Zero is the intended default of every appended staker field (no lock, fully vested,
+/// no delegate).
+pub fn upgrade_staker(data: &[u8]) -> Result<Staker> {
+    let mut staker: Staker = upgrade_account_data(data, 8 + Staker::INIT_SPACE)?;
+
+    if staker.version >= STAKER_VERSION {
+        return Err(OxediumError::AccountAlreadyMigrated.into());
+    }
+
+    staker.version = STAKER_VERSION;
+
+    Ok(staker)
+}
+
+/// Reallocates an account to `space` bytes and writes the upgraded state into it.
+///
+/// The payer tops up the rent-exempt minimum for the new size.
+///
+/// # Arguments
+/// * `account` - The account being migrated (owned by this program)
+/// * `payer` - Signer paying the extra rent
+/// * `system_program` - System program, used for the rent transfer
+/// * `space` - Size of the current layout, including the discriminator
+/// * `upgraded` - The account state in the current layout
+pub fn write_upgraded_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    upgraded: &T,
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if rent_exempt > lamports {
+        let cpi_accounts = system_program::Transfer { from: payer.clone(), to: account.clone() };
+        system_program::transfer(CpiContext::new(system_program.clone(), cpi_accounts), rent_exempt - lamports)?;
+    }
+
+    account.resize(space)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    upgraded.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::states::Vault;
+
+/// Calculates the fee (in basis points) for moving stake from one vault to another.
+///
+/// A migration burns LP of `vault_from` and mints LP of `vault_to`, so stake moves
+/// towards the destination vault. Migrations into vaults that are relatively more
+/// drained bring liquidity claims where swaps have taken tokens out and are favored.
+///
+/// Fee logic:
+/// - If the destination vault is in relatively deeper deficit than the source
+///   (delta_to_bps <= delta_from_bps), half of the destination base fee is applied.
+/// - Otherwise the destination base fee applies, growing non-linearly (quadratic curve)
+///   with the source vault's deficit, as migrating out of a drained vault worsens it.
+///
+/// # Arguments
+/// * `vault_from` - The vault whose LP tokens are burned
+/// * `vault_to`   - The vault whose LP tokens are minted
+///
+/// # Returns
+/// * `u64` - The calculated migration fee in basis points (bps)
+pub fn migrate_fee_bps(
+    vault_from: &Vault,
+    vault_to: &Vault,
+) -> u64 {
+    let delta_from_bps: i128 =
+        (vault_from.current_liquidity as i128 - vault_from.initial_liquidity as i128)
+            * 10_000
+            / vault_from.initial_liquidity as i128;
+
+    let delta_to_bps: i128 = if vault_to.initial_liquidity == 0 {
+        // An empty destination vault is neither in surplus nor in deficit
+        0
+    } else {
+        (vault_to.current_liquidity as i128 - vault_to.initial_liquidity as i128)
+            * 10_000
+            / vault_to.initial_liquidity as i128
+    };
+
+    if delta_to_bps <= delta_from_bps {
+        return vault_to.base_fee / 2;
+    }
+
+    // Deficit of the source vault (0..10_000 bps); a source in surplus has none
+    let deficit_bps: u64 = (-delta_from_bps).clamp(0, 10_000) as u64;
+
+    // Same quadratic curve as `fees_setting`, scaled to 0..10_000
+    let curved_deficit_bps = deficit_bps * deficit_bps / 10_000;
+
+    const MAX_FEE_BPS: u64 = 10_000;
+
+    vault_to.base_fee
+        + (MAX_FEE_BPS - vault_to.base_fee)
+            * curved_deficit_bps
+            / 10_000
+}
@@ -5,6 +5,25 @@ pub use raw_amount_out::*;
 pub use fees_setting::*;
 pub use compute_swap_math::*;
 pub use conf_fee_bps::*;
+pub use settle_staker_yield::*;
+pub use migrate_fee_bps::*;
+pub use update_reward_stream::*;
+pub use settle_staker_rewards::*;
+pub use accrue_vault_yield::*;
+pub use lock_boost::*;
+pub use drip_vault_yield::*;
+pub use deposit_value::*;
+pub use merkle_proof::*;
+pub use emergency_withdraw_amount::*;
+pub use unstake_fee_bps::*;
+pub use set_role_holder::*;
+pub use apply_parameter_change::*;
+pub use multisig::*;
+pub use split_protocol_yield::*;
+pub use migrate_account::*;
+pub use claim_group::*;
+pub use register_staker::*;
+pub use apply_swap_result::*;
 
 pub mod calculate_staker_yield;
 pub mod chekers;
@@ -12,4 +31,23 @@ pub mod calculate_fee_amount;
 pub mod raw_amount_out;
 pub mod fees_setting;
 pub mod compute_swap_math;
-pub mod conf_fee_bps;
\ No newline at end of file
+pub mod conf_fee_bps;
+pub mod settle_staker_yield;
+pub mod migrate_fee_bps;
+pub mod update_reward_stream;
+pub mod settle_staker_rewards;
+pub mod accrue_vault_yield;
+pub mod lock_boost;
+pub mod drip_vault_yield;
+pub mod deposit_value;
+pub mod merkle_proof;
+pub mod emergency_withdraw_amount;
+pub mod unstake_fee_bps;
+pub mod set_role_holder;
+pub mod apply_parameter_change;
+pub mod multisig;
+pub mod split_protocol_yield;
+pub mod migrate_account;
+pub mod claim_group;
+pub mod register_staker;
+pub mod apply_swap_result;
\ No newline at end of file
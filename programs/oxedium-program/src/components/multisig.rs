@@ -0,0 +1,61 @@
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use crate::{instruction, states::Multisig, utils::OxediumError};
+
+/// Returns the position of `key` in the multisig signer set.
+///
+/// # Arguments
+/// * `multisig` - The multisig holding the signer set
+/// * `key` - The key to look up
+///
+/// # Returns
+/// * `Result<usize, OxediumError>` - The index of the signer, or `NotMultisigSigner`
+pub fn multisig_signer_index(multisig: &Multisig, key: &Pubkey) -> Result<usize, OxediumError> {
+    multisig.signers.iter()
+        .position(|signer| signer == key)
+        .ok_or(OxediumError::NotMultisigSigner)
+}
+
+/// Counts the approvals collected by a proposal.
+///
+/// # Arguments
+/// * `approvals` - One flag per multisig signer
+pub fn count_approvals(approvals: &[bool]) -> usize {
+    approvals.iter().filter(|approved| **approved).count()
+}
+
+/// Checks whether instruction data targets an action the multisig may execute.
+///
+/// Covers every instruction gated on the admin (or a role the admin holds), so nothing
+/// becomes unreachable once the multisig takes over the admin role, plus
+/// `change_multisig` so it can rotate its own signers.
+///
+/// # Arguments
+/// * `data` - Instruction data, starting with the Anchor discriminator
+pub fn is_whitelisted_action(data: &[u8]) -> bool {
+    let whitelist: [&[u8]; 22] = [
+        instruction::UpdateVault::DISCRIMINATOR,
+        instruction::UpdateTreasury::DISCRIMINATOR,
+        instruction::SetStoptap::DISCRIMINATOR,
+        instruction::Collect::DISCRIMINATOR,
+        instruction::SetFeeConfig::DISCRIMINATOR,
+        instruction::SetParameterBounds::DISCRIMINATOR,
+        instruction::InitVault::DISCRIMINATOR,
+        instruction::SetVaultStatus::DISCRIMINATOR,
+        instruction::SetVaultAllowlist::DISCRIMINATOR,
+        instruction::CloseVault::DISCRIMINATOR,
+        instruction::CreateRewardStream::DISCRIMINATOR,
+        instruction::FundRewardStream::DISCRIMINATOR,
+        instruction::ExtendRewardStream::DISCRIMINATOR,
+        instruction::CancelRewardStream::DISCRIMINATOR,
+        instruction::GrantRole::DISCRIMINATOR,
+        instruction::RevokeRole::DISCRIMINATOR,
+        instruction::QueueChange::DISCRIMINATOR,
+        instruction::CancelChange::DISCRIMINATOR,
+        instruction::ProposeAdmin::DISCRIMINATOR,
+        instruction::AcceptAdmin::DISCRIMINATOR,
+        instruction::CancelAdminProposal::DISCRIMINATOR,
+        instruction::ChangeMultisig::DISCRIMINATOR,
+    ];
+
+    whitelist.iter().any(|discriminator| data.starts_with(discriminator))
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::Pubkey;
+use crate::{states::{Staker, Vault}, utils::STAKER_VERSION};
+
+/// Records the owner and vault of a Staker account (regular or NFT position).
+///
+/// A freshly created account (version 0) is counted in the vault's `open_stakers`,
+/// so the vault cannot be closed while any Staker account of it may still hold a claim.
+///
+/// # Arguments
+/// * `vault` - The vault the staker belongs to
+/// * `staker` - The Staker account
+/// * `owner` - The staker's owner (or position NFT mint)
+/// * `vault_mint` - The vault token mint
+pub fn register_staker(vault: &mut Vault, staker: &mut Staker, owner: Pubkey, vault_mint: Pubkey) {
+    if staker.version == 0 {
+        vault.open_stakers += 1;
+        staker.version = STAKER_VERSION;
+    }
+
+    staker.owner = owner;
+    staker.vault = vault_mint;
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::states::{Role, Treasury};
+
+/// Writes `holder` into the treasury slot of `role`.
+///
+/// # Arguments
+/// * `treasury` - The treasury storing the role holders
+/// * `role` - The role to update
+/// * `holder` - The new holder; `Pubkey::default()` revokes the role
+///
+/// # Returns
+/// * `Pubkey` - The previous holder of the role
+pub fn set_role_holder(treasury: &mut Treasury, role: Role, holder: Pubkey) -> Pubkey {
+    let slot: &mut Pubkey = match role {
+        Role::Pauser => &mut treasury.pauser,
+        Role::FeeManager => &mut treasury.fee_manager,
+        Role::VaultLister => &mut treasury.vault_lister,
+        Role::FeeCollector => &mut treasury.fee_collector,
+        Role::Guardian => &mut treasury.guardian,
+    };
+
+    std::mem::replace(slot, holder)
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{components::{calculate_staker_yield, update_reward_stream}, states::{RewardStream, Staker, Vault}, utils::OxediumError};
+
+/// Accrues the vault's reward stream and moves the rewards a staker has earned
+/// since their last checkpoint into `pending_rewards`.
+///
+/// Does nothing for vaults without a reward stream. For vaults with one, the stream
+/// account must be provided, otherwise LP balance changes would escape settlement.
+/// Must be called before the staker's LP balance and the vault's LP supply change.
+///
+/// # Arguments
+/// * `vault` - The vault the staker provides liquidity to
+/// * `stream` - The vault's reward stream, if passed to the instruction
+/// * `staker` - The staker PDA to settle
+/// * `staker_lp` - The staker's liquid LP token balance prior to the balance change
+/// * `current_timestamp` - The current unix timestamp
+pub fn settle_staker_rewards(
+    vault: &Vault,
+    stream: Option<&mut RewardStream>,
+    staker: &mut Staker,
+    staker_lp: u64,
+    current_timestamp: i64,
+) -> Result<()> {
+    if !vault.has_reward_stream {
+        return Ok(());
+    }
+
+    let stream = stream.ok_or(OxediumError::MissingRewardStream)?;
+
+    // Total LP supply equals the vault's initial liquidity
+    update_reward_stream(stream, vault.initial_liquidity, current_timestamp)?;
+
+    let cumulative_reward: u128 = stream.cumulative_reward_per_lp;
+
+    // Locked LP keeps earning rewards at face value
+    let staker_lp: u64 = staker_lp + staker.locked_amount;
+
+    staker.pending_rewards += calculate_staker_yield(cumulative_reward, staker_lp, staker.last_cumulative_reward);
+    staker.last_cumulative_reward = cumulative_reward;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::{components::{accrue_vault_yield, boost_weight, calculate_staker_yield, drip_vault_yield, unexpired_boost_yield}, states::{Staker, Vault}};
+
+/// Moves the yield a staker has earned since their last checkpoint into `pending_claim`
+/// and advances the checkpoints to the vault's current cumulative values.
+///
+/// Swap yield is earned on the staker's LP weight: the liquid LP balance plus the
+/// locked amount with its boost. Early-unlock penalties are earned on the liquid
+/// LP balance only, so they flow to non-locked stakers.
+///
+/// The boost ends at `lock_end`: on the first settlement after it, the boost's yield
+/// since the previous settlement is kept pro-rata up to `lock_end`, the rest is paid out
+/// to the vault's LP weight, and the boost weight is removed from the vault. The LP
+/// itself stays locked (unboosted) until `unlock_stake`.
+///
+/// While the position is younger than the vault's `min_stake_age`, earned yield is
+/// held in `unvested_claim`; it moves to `pending_claim` on the first settlement
+/// after the position has aged, or is forfeited on early exit.
+///
+/// Must be called with the staker's LP balance *before* it changes (mint/burn/lock),
+/// otherwise the new balance would be credited with yield it did not earn.
+///
+/// # Arguments
+/// * `vault` - The vault the staker provides liquidity to
+/// * `staker` - The staker PDA to settle
+/// * `staker_lp` - The staker's liquid LP token balance prior to the balance change
+/// * `current_timestamp` - The current unix timestamp
+pub fn settle_staker_yield(vault: &mut Vault, staker: &mut Staker, staker_lp: u64, current_timestamp: i64) {
+    // Bring the accumulator up to date with dripped fees
+    drip_vault_yield(vault, current_timestamp);
+
+    let cumulative_yield: u128 = vault.cumulative_yield_per_lp;
+    let cumulative_penalty: u128 = vault.cumulative_penalty_per_lp;
+
+    let boost: u64 = boost_weight(staker.locked_amount, staker.boost_bps);
+    let boost_yield: u64 = calculate_staker_yield(cumulative_yield, boost, staker.last_cumulative_yield);
+
+    let mut earned: u64 = calculate_staker_yield(cumulative_yield, staker_lp + staker.locked_amount, staker.last_cumulative_yield)
+        + calculate_staker_yield(cumulative_penalty, staker_lp, staker.last_cumulative_penalty);
+    staker.last_cumulative_yield = cumulative_yield;
+    staker.last_cumulative_penalty = cumulative_penalty;
+
+    if boost > 0 && current_timestamp >= staker.lock_end {
+        let kept: u64 = unexpired_boost_yield(boost_yield, staker.last_settle_time, staker.lock_end, current_timestamp);
+        earned += kept;
+
+        // Expire the boost, then pay its post-expiry yield out as if it had been removed
+        // at `lock_end` (the staker's unboosted weight shares in it)
+        vault.total_boost_weight -= boost;
+        staker.boost_bps = 10_000;
+
+        if boost_yield > kept {
+            // Already counted in `unclaimed_yield`; accruing counts it again
+            vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(boost_yield - kept);
+            accrue_vault_yield(vault, boost_yield - kept, current_timestamp);
+        }
+    } else {
+        earned += boost_yield;
+    }
+
+    staker.last_settle_time = current_timestamp;
+
+    if current_timestamp - staker.stake_time < vault.min_stake_age {
+        staker.unvested_claim += earned;
+    } else {
+        staker.pending_claim += staker.unvested_claim + earned;
+        staker.unvested_claim = 0;
+    }
+}
+
+/// Returns the exiting share of a staker's unvested yield to the vault's remaining stakers.
+///
+/// Unvested yield is forfeited pro-rata to the LP leaving the position; the rest stays
+/// unvested on the remaining LP.
+///
+/// # Arguments
+/// * `vault` - The vault the staker exits from
+/// * `staker` - The exiting staker
+/// * `withdrawn` - LP leaving the position
+/// * `position` - The staker's LP (liquid and locked) before the exit; a full exit forfeits everything
+/// * `current_timestamp` - The current unix timestamp
+///
+/// # Returns
+/// * `u64` - The forfeited amount
+pub fn forfeit_unvested_yield(vault: &mut Vault, staker: &mut Staker, withdrawn: u64, position: u64, current_timestamp: i64) -> u64 {
+    let forfeited: u64 = if withdrawn >= position {
+        staker.unvested_claim
+    } else {
+        (staker.unvested_claim as u128 * withdrawn as u128 / position as u128) as u64
+    };
+    staker.unvested_claim -= forfeited;
+
+    if forfeited > 0 {
+        // Already counted in `unclaimed_yield`; accruing counts it again
+        vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(forfeited);
+        accrue_vault_yield(vault, forfeited, current_timestamp);
+    }
+
+    forfeited
+}
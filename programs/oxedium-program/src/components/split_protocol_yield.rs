@@ -0,0 +1,34 @@
+use crate::{states::FeeRecipient, utils::OxediumError};
+
+/// Splits collected protocol yield between fee recipients by weight.
+///
+/// Each recipient gets `amount * weight_bps / 10_000` rounded down; the rounding dust
+/// goes to the last recipient so the whole amount is always distributed.
+///
+/// # Arguments
+/// * `amount` - The protocol yield being collected
+/// * `recipients` - The configured recipients (weights validated to sum to 10_000)
+///
+/// # Returns
+/// * `Result<Vec<u64>, OxediumError>` - One share per recipient, in order
+pub fn split_protocol_yield(amount: u64, recipients: &[FeeRecipient]) -> Result<Vec<u64>, OxediumError> {
+    let mut shares: Vec<u64> = Vec::with_capacity(recipients.len());
+    let mut distributed: u64 = 0;
+
+    for (i, recipient) in recipients.iter().enumerate() {
+        let share = if i + 1 == recipients.len() {
+            amount.checked_sub(distributed).ok_or(OxediumError::OverflowInSub)?
+        } else {
+            let share = (amount as u128)
+                .checked_mul(recipient.weight_bps as u128)
+                .ok_or(OxediumError::OverflowInMul)?
+                / 10_000;
+            u64::try_from(share).map_err(|_| OxediumError::OverflowInCast)?
+        };
+
+        distributed = distributed.checked_add(share).ok_or(OxediumError::OverflowInAdd)?;
+        shares.push(share);
+    }
+
+    Ok(shares)
+}
@@ -0,0 +1,27 @@
+use crate::states::{Vault, VaultStatus};
+
+/// Calculates the extra unstake fee (in basis points) for a vault.
+///
+/// A 2% fee applies while the vault's current liquidity is below 50% of its LP supply,
+/// so the last LPs of a drained vault are not left with the deficit. Vaults being delisted
+/// never charge it, so LPs can leave a retired asset at full value.
+///
+/// # Arguments
+/// * `vault` - The vault being unstaked from
+///
+/// # Returns
+/// * `u64` - The extra fee in basis points
+pub fn unstake_fee_bps(vault: &Vault) -> u64 {
+    if vault.status != VaultStatus::Active {
+        return 0;
+    }
+
+    let liquidity_ratio = (vault.current_liquidity as u128 * 100) / vault.initial_liquidity as u128; // in %
+
+    // Apply extra fee if current liquidity < 50%
+    if liquidity_ratio < 50 {
+        return 200; // 2% extra fee if liquidity too low
+    }
+
+    0
+}
@@ -0,0 +1,47 @@
+use crate::{states::RewardStream, utils::{OxediumError, SCALE}};
+
+/// Accrues the rewards emitted since the stream was last updated into its
+/// cumulative reward per LP token.
+///
+/// Emission only happens inside `[start_time, end_time]`, is capped by the funded
+/// amount that has not been emitted yet, and is skipped while there is no LP supply
+/// (those rewards stay in escrow and can be refunded on cancel).
+///
+/// # Arguments
+/// * `stream` - The reward stream to update
+/// * `total_lp` - The vault's LP supply during the elapsed period
+/// * `current_timestamp` - The current unix timestamp
+///
+/// # Returns
+/// * `Result<(), OxediumError>` - `Overflow` if the accumulator overflows
+pub fn update_reward_stream(
+    stream: &mut RewardStream,
+    total_lp: u64,
+    current_timestamp: i64,
+) -> Result<(), OxediumError> {
+    let now = current_timestamp.min(stream.end_time);
+    let from = stream.last_update_time.max(stream.start_time);
+
+    if now <= from {
+        return Ok(());
+    }
+
+    if total_lp > 0 {
+        // Rewards emitted over the elapsed period, capped by the remaining funding
+        let remaining = stream.funded_amount.saturating_sub(stream.emitted_amount);
+        let emission = ((now - from) as u128)
+            .checked_mul(stream.emission_per_second as u128)
+            .ok_or(OxediumError::Overflow)?
+            .min(remaining as u128);
+
+        // Spread the emission over the LP supply
+        stream.cumulative_reward_per_lp = stream.cumulative_reward_per_lp
+            .checked_add(emission * SCALE / total_lp as u128)
+            .ok_or(OxediumError::Overflow)?;
+        stream.emitted_amount += emission as u64;
+    }
+
+    stream.last_update_time = now;
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AdminChanged {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::states::ParameterChange;
+
+#[event]
+pub struct ChangeCancelledEvent {
+    pub id: u64,
+    pub canceller: Pubkey,
+    pub change: ParameterChange
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::states::ParameterChange;
+
+#[event]
+pub struct ChangeExecutedEvent {
+    pub id: u64,
+    pub executor: Pubkey,
+    pub change: ParameterChange
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use crate::states::ParameterChange;
+
+#[event]
+pub struct ChangeQueuedEvent {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub change: ParameterChange,
+    pub eta: i64
+}
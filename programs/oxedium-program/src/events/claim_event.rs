@@ -4,5 +4,6 @@ use anchor_lang::prelude::*;
 pub struct ClaimEvent {
     pub user: Pubkey,
     pub mint: Pubkey,
+    pub recipient: Pubkey,
     pub amount: u64
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct EmergencyWithdrawEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub lp_amount: u64,
+    pub amount: u64
+}
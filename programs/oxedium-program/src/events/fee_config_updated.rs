@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+use crate::states::FeeRecipient;
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub signer: Pubkey,
+    pub recipients: Vec<FeeRecipient>
+}
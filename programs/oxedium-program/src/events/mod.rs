@@ -2,8 +2,58 @@ pub use swap_event::*;
 pub use staking_event::*;
 pub use unstaking_event::*;
 pub use claim_event::*;
+pub use unstake_into_event::*;
+pub use zap_stake_event::*;
+pub use migrate_stake_event::*;
+pub use reward_claim_event::*;
+pub use lock_event::*;
+pub use unlock_event::*;
+pub use emergency_withdraw_event::*;
+pub use claim_as_event::*;
+pub use change_queued_event::*;
+pub use change_executed_event::*;
+pub use change_cancelled_event::*;
+pub use vault_initialized::*;
+pub use vault_updated::*;
+pub use treasury_updated::*;
+pub use protocol_fee_collected::*;
+pub use admin_changed::*;
+pub use paused::*;
+pub use role_changed::*;
+pub use parameter_bounds_updated::*;
+pub use vault_status_changed::*;
+pub use vault_closed::*;
+pub use fee_config_updated::*;
+pub use admin_proposed::*;
+pub use multisig_changed::*;
+pub use timelock_delay_updated::*;
 
 pub mod swap_event;
 pub mod staking_event;
 pub mod unstaking_event;
-pub mod claim_event;
\ No newline at end of file
+pub mod claim_event;
+pub mod unstake_into_event;
+pub mod zap_stake_event;
+pub mod migrate_stake_event;
+pub mod reward_claim_event;
+pub mod lock_event;
+pub mod unlock_event;
+pub mod emergency_withdraw_event;
+pub mod claim_as_event;
+pub mod change_queued_event;
+pub mod change_executed_event;
+pub mod change_cancelled_event;
+pub mod vault_initialized;
+pub mod vault_updated;
+pub mod treasury_updated;
+pub mod protocol_fee_collected;
+pub mod admin_changed;
+pub mod paused;
+pub mod role_changed;
+pub mod parameter_bounds_updated;
+pub mod vault_status_changed;
+pub mod vault_closed;
+pub mod fee_config_updated;
+pub mod admin_proposed;
+pub mod multisig_changed;
+pub mod timelock_delay_updated;
\ No newline at end of file
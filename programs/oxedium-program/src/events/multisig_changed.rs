@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MultisigChanged {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub owner_set_seqno: u32
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+use crate::instructions::admin::ParameterBounds;
+
+#[event]
+pub struct ParameterBoundsUpdated {
+    pub old: ParameterBounds,
+    pub new: ParameterBounds
+}
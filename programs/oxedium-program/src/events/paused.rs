@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct Paused {
+    pub signer: Pubkey,
+    pub stoptap: bool
+}
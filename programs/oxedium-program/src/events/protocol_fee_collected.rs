@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProtocolFeeCollected {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub weight_bps: u64,
+    pub amount: u64
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RewardClaimEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::states::Role;
+
+#[event]
+pub struct RoleChanged {
+    pub role: Role,
+    pub previous_holder: Pubkey,
+    pub holder: Pubkey
+}
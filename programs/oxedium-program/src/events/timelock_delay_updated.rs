@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub old_delay: i64,
+    pub delay: i64
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TreasuryUpdated {
+    pub old_fee_bps: u64,
+    pub fee_bps: u64,
+    pub old_deviation: u64,
+    pub deviation: u64
+}
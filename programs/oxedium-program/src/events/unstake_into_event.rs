@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UnstakeIntoEvent {
+    pub user: Pubkey,
+    pub fee_bps: u64,
+    pub token_in: Pubkey,
+    pub token_out: Pubkey,
+    pub lp_amount: u64,
+    pub amount_out: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64
+}
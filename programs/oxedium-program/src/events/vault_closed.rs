@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct VaultClosed {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub dust: u64
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct VaultInitialized {
+    pub lister: Pubkey,
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub pyth_price_account: Pubkey,
+    pub base_fee: u64,
+    pub max_age_price: u64
+}
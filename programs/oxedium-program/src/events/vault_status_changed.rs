@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::states::VaultStatus;
+
+#[event]
+pub struct VaultStatusChanged {
+    pub mint: Pubkey,
+    pub old_status: VaultStatus,
+    pub status: VaultStatus
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use crate::states::VaultParams;
+
+#[event]
+pub struct VaultUpdated {
+    pub mint: Pubkey,
+    pub old: VaultParams,
+    pub new: VaultParams
+}
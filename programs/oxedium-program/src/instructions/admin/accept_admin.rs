@@ -0,0 +1,45 @@
+use crate::{components::check_pending_admin, events::AdminChanged, states::Treasury, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Accept the admin role (step 2 of the admin handover)
+///
+/// Must be signed by the pending admin set in `propose_admin`, which proves the new key
+/// is controlled before the old admin loses access.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn accept_admin(ctx: Context<AcceptAdminInstructionAccounts>) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the proposed admin
+    check_pending_admin(treasury, &ctx.accounts.signer.key())?;
+
+    let previous_admin = treasury.admin;
+    treasury.admin = treasury.pending_admin;
+    treasury.pending_admin = Pubkey::default();
+
+    emit!(AdminChanged {
+        previous_admin,
+        admin: treasury.admin
+    });
+
+    Ok(())
+}
+
+/// Accounts context for `accept_admin` instruction
+#[derive(Accounts)]
+pub struct AcceptAdminInstructionAccounts<'info> {
+    /// The signer of the transaction (must be the pending admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,47 @@
+use crate::{components::multisig_signer_index, states::{Multisig, Proposal}, utils::{OxediumError, MULTISIG_SEED, PROPOSAL_SEED}};
+use anchor_lang::prelude::*;
+
+/// Approve a pending multisig proposal
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn approve_proposal(ctx: Context<ApproveProposalInstructionAccounts>) -> Result<()> {
+    let multisig: &Account<'_, Multisig> = &ctx.accounts.multisig_pda;
+    let proposal: &mut Account<'_, Proposal> = &mut ctx.accounts.proposal_pda;
+
+    require!(proposal.owner_set_seqno == multisig.owner_set_seqno, OxediumError::StaleProposal);
+    require!(!proposal.executed, OxediumError::ProposalAlreadyExecuted);
+
+    let index = multisig_signer_index(multisig, &ctx.accounts.signer.key())?;
+    proposal.approvals[index] = true;
+
+    msg!("ApproveProposal {{id: {}, signer: {}}}", proposal.id, ctx.accounts.signer.key());
+
+    Ok(())
+}
+
+/// Accounts context for the `approve_proposal` instruction
+#[derive(Accounts)]
+pub struct ApproveProposalInstructionAccounts<'info> {
+    /// The signer of the transaction (must be a multisig signer)
+    pub signer: Signer<'info>,
+
+    /// The Multisig PDA account
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [MULTISIG_SEED.as_bytes()], bump)]
+    pub multisig_pda: Account<'info, Multisig>,
+
+    /// The Proposal PDA account
+    ///
+    /// Seeds:
+    /// - `PROPOSAL_SEED`
+    /// - `proposal_pda.id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [PROPOSAL_SEED.as_bytes(), proposal_pda.id.to_le_bytes().as_ref()], bump)]
+    pub proposal_pda: Account<'info, Proposal>,
+}
@@ -0,0 +1,41 @@
+use crate::{components::check_admin, states::Treasury, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Cancel a pending admin handover
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposalInstructionAccounts>) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the treasury admin
+    check_admin(treasury, &ctx.accounts.signer)?;
+
+    let cancelled = treasury.pending_admin;
+    treasury.pending_admin = Pubkey::default();
+
+    msg!("CancelAdminProposal {{admin: {}, cancelled: {}}}",
+        treasury.admin,
+        cancelled
+    );
+
+    Ok(())
+}
+
+/// Accounts context for `cancel_admin_proposal` instruction
+#[derive(Accounts)]
+pub struct CancelAdminProposalInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,65 @@
+use crate::{components::check_role, events::ChangeCancelledEvent, states::{QueuedChange, Role, Treasury}, utils::{OXEDIUM_SEED, QUEUED_CHANGE_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+
+/// Cancel a queued parameter change
+///
+/// Allowed for the fee manager and the guardian (and the admin). The queued change is
+/// closed and its rent returned to the proposer.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn cancel_change(ctx: Context<CancelChangeInstructionAccounts>) -> Result<()> {
+    let treasury: &Account<'_, Treasury> = &ctx.accounts.treasury_pda;
+    let signer = ctx.accounts.signer.key();
+
+    check_role(treasury, Role::FeeManager, &signer)
+        .or_else(|_| check_role(treasury, Role::Guardian, &signer))?;
+
+    let queued: &Account<'_, QueuedChange> = &ctx.accounts.queued_change;
+
+    emit!(ChangeCancelledEvent {
+        id: queued.id,
+        canceller: signer,
+        change: queued.change
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `cancel_change` instruction
+#[derive(Accounts)]
+pub struct CancelChangeInstructionAccounts<'info> {
+    /// The signer of the transaction (fee manager, guardian or admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The proposer of the change, receives the rent of the closed queued change
+    /// CHECK: constrained to the proposer recorded in `queued_change`
+    #[account(mut, address = queued_change.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// The queued change PDA, closed on cancellation
+    ///
+    /// Seeds:
+    /// - `QUEUED_CHANGE_SEED`
+    /// - `queued_change.id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    #[account(
+        mut,
+        seeds = [QUEUED_CHANGE_SEED.as_bytes(), queued_change.id.to_le_bytes().as_ref()],
+        bump,
+        close = proposer,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// The Treasury PDA account used for role checks
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
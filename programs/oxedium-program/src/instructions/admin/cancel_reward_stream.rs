@@ -0,0 +1,83 @@
+use crate::{components::{check_admin, update_reward_stream}, states::{RewardStream, Treasury, Vault}, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+/// Stop a reward stream and refund the funding that has not been emitted yet
+///
+/// Rewards already accrued to LPs stay in escrow and remain claimable.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn cancel_reward_stream(ctx: Context<CancelRewardStreamInstructionAccounts>) -> Result<()> {
+    // Ensure the caller is an admin using the Treasury account
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Accrue everything emitted up to now, then stop emissions
+    update_reward_stream(stream, ctx.accounts.vault_pda.initial_liquidity, current_timestamp)?;
+    stream.end_time = stream.end_time.min(current_timestamp.max(stream.start_time));
+
+    let refund: u64 = stream.funded_amount - stream.emitted_amount;
+
+    // Prepare PDA seeds for signing the transfer from escrow
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reward_escrow.to_account_info(),
+        to: ctx.accounts.signer_reward_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info()
+    };
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds
+        ),
+        refund
+    )?;
+
+    stream.funded_amount = stream.emitted_amount;
+
+    // Log the cancellation for transparency
+    msg!("CancelRewardStream {{mint: {}, refund: {}}}", ctx.accounts.vault_mint.key(), refund);
+
+    Ok(())
+}
+
+/// Accounts context for the `cancel_reward_stream` instruction
+#[derive(Accounts)]
+pub struct CancelRewardStreamInstructionAccounts<'info> {
+    /// The signer of the transaction (must be admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The admin's reward token account receiving the refund
+    #[account(mut, token::authority = signer, token::mint = reward_stream.reward_mint)]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    /// The Vault PDA account
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The RewardStream PDA account
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Account<'info, RewardStream>,
+
+    /// Escrow token account holding the stream funding
+    #[account(mut, seeds = [REWARD_ESCROW_SEED.as_bytes(), reward_stream.key().as_ref()], bump)]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    /// The Treasury PDA account used for admin checks and escrow authority
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// Token program required to execute token transfers
+    pub token_program: Program<'info, Token>,
+}
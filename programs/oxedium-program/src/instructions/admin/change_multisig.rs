@@ -0,0 +1,49 @@
+use crate::{components::check_multisig_config, events::MultisigChanged, states::Multisig, utils::{MULTISIG_SEED, MULTISIG_SIGNER_SEED}};
+use anchor_lang::prelude::*;
+
+/// Replace the multisig signer set and threshold
+///
+/// Can only be signed by the multisig signer PDA, i.e. executed through an approved
+/// proposal. Outstanding proposals of the old signer set become stale.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `signers` - new signer set (at most `MAX_MULTISIG_SIGNERS`)
+/// * `threshold` - new approval threshold
+pub fn change_multisig(
+    ctx: Context<ChangeMultisigInstructionAccounts>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    check_multisig_config(&signers, threshold)?;
+
+    let multisig: &mut Account<'_, Multisig> = &mut ctx.accounts.multisig_pda;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.owner_set_seqno = multisig.owner_set_seqno.wrapping_add(1);
+
+    emit!(MultisigChanged {
+        signers: multisig.signers.clone(),
+        threshold: multisig.threshold,
+        owner_set_seqno: multisig.owner_set_seqno
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `change_multisig` instruction
+#[derive(Accounts)]
+pub struct ChangeMultisigInstructionAccounts<'info> {
+    /// The multisig signer PDA (signs via `execute_proposal`)
+    #[account(seeds = [MULTISIG_SIGNER_SEED.as_bytes(), multisig_pda.key().as_ref()], bump = multisig_pda.signer_bump)]
+    pub multisig_signer: Signer<'info>,
+
+    /// The Multisig PDA account
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [MULTISIG_SEED.as_bytes()], bump)]
+    pub multisig_pda: Account<'info, Multisig>,
+}
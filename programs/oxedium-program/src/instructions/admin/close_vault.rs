@@ -0,0 +1,106 @@
+use crate::{components::{check_admin, check_vault_closable}, events::VaultClosed, states::{Treasury, Vault}, utils::{OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED}};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+/// Close a retired, empty vault and refund its rent
+///
+/// The vault must be `WithdrawOnly`, have no LP supply, buffered fees or uncollected
+/// protocol yield left, and every Staker account of it must be closed (which pays out its
+/// claims). What is left in the treasury token account is then rounding dust; it is swept
+/// to the admin, then the token account and the Vault PDA are closed with rent going to
+/// the admin.
+///
+/// The LP mint stays: legacy SPL Token mints cannot be closed. Its existence keeps
+/// `init_vault` from listing the token again. A reward stream of the vault, if any, is
+/// not closed either.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn close_vault(ctx: Context<CloseVaultInstructionAccounts>) -> Result<()> {
+    // Ensure the signer is the treasury admin
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    let vault: &Account<'_, Vault> = &ctx.accounts.vault_pda;
+
+    check_vault_closable(vault, ctx.accounts.lp_mint.supply)?;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Sweep rounding dust so the treasury token account can be closed
+    let dust = ctx.accounts.treasury_ata.amount;
+
+    if dust > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_ata.to_account_info(),
+            to: ctx.accounts.signer_ata.to_account_info(),
+            authority: ctx.accounts.treasury_pda.to_account_info(),
+        };
+        token::transfer(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds), dust)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.treasury_ata.to_account_info(),
+        destination: ctx.accounts.signer.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds))?;
+
+    emit!(VaultClosed {
+        admin: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        dust
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `close_vault` instruction
+#[derive(Accounts)]
+pub struct CloseVaultInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin); receives the rent
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The vault's LP mint, must have zero supply
+    #[account(address = vault_pda.lp_mint)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account, closed by this instruction
+    ///
+    /// Seeds:
+    /// - `VAULT_SEED`
+    /// - vault_mint key
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump, close = signer)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The Treasury PDA account used for admin checks and as token account authority
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// Treasury's token account of the vault token, closed by this instruction
+    #[account(
+        mut,
+        associated_token::mint = vault_mint,
+        associated_token::authority = treasury_pda,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    /// The admin's token account receiving the dust
+    #[account(mut, token::mint = vault_mint, token::authority = signer)]
+    pub signer_ata: Account<'info, TokenAccount>,
+
+    /// Token program required to execute token transfers
+    pub token_program: Program<'info, Token>,
+}
@@ -1,65 +1,88 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::{components::check_admin, states::{Treasury, Vault}, utils::{TREASURY_SEED, OXEDIUM_SEED, VAULT_SEED}};
+use crate::{components::split_protocol_yield, events::ProtocolFeeCollected, states::{FeeConfig, Treasury, Vault}, utils::{OxediumError, FEE_CONFIG_SEED, TREASURY_SEED, OXEDIUM_SEED, VAULT_SEED}};
 
-/// Collect the protocol yield from a vault to the admin's token account
+/// Distribute the protocol yield of a vault to the configured fee recipients
+///
+/// Permissionless: anyone can trigger the distribution. `remaining_accounts` holds one
+/// token account of the vault token per recipient of the `FeeConfig`, in the configured
+/// order, each owned by that recipient's wallet.
 ///
 /// # Arguments
 /// * `ctx` - context containing all accounts required for this instruction
-pub fn collect(ctx: Context<CollectInstructionAccounts>) -> Result<()> {
-    // Ensure the caller is the treasury admin
-    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+pub fn collect<'info>(ctx: Context<'_, '_, 'info, 'info, CollectInstructionAccounts<'info>>) -> Result<()> {
+    let recipients = &ctx.accounts.fee_config.recipients;
+    let remaining = ctx.remaining_accounts;
+
+    if remaining.len() != recipients.len() {
+        return Err(OxediumError::InvalidRemainingAccounts.into());
+    }
 
     let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
 
     // Read the protocol yield accumulated in the vault
     let protocol_yield = vault.protocol_yield;
+    let shares = split_protocol_yield(protocol_yield, recipients)?;
 
     // Prepare PDA seeds for signing the transfer from treasury ATA
     let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
     let signer_seeds = &[&seeds[..]]; // wrapped as slice for CPI
 
-    // Define the token transfer instruction
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.treasury_ata.to_account_info(),   // treasury token account (source)
-        to: ctx.accounts.signer_ata.to_account_info(),      // admin's token account (destination)
-        authority: ctx.accounts.treasury_pda.to_account_info() // PDA authority
-    };
-
-    // Perform the transfer using CPI with PDA signer
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            signer_seeds
-        ),
-        protocol_yield
-    )?;
+    for ((recipient, share), recipient_info) in recipients.iter().zip(shares).zip(remaining.iter()) {
+        let recipient_ata: Account<'info, TokenAccount> = Account::try_from(recipient_info)?;
+
+        // Each share must land in a token account of the vault token owned by the recipient
+        if recipient_ata.mint != vault.token_mint || recipient_ata.owner != recipient.owner {
+            return Err(OxediumError::InvalidRemainingAccounts.into());
+        }
+
+        if share > 0 {
+            // Define the token transfer instruction
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.treasury_ata.to_account_info(),   // treasury token account (source)
+                to: recipient_info.clone(),                          // recipient's token account (destination)
+                authority: ctx.accounts.treasury_pda.to_account_info() // PDA authority
+            };
+
+            // Perform the transfer using CPI with PDA signer
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds
+                ),
+                share
+            )?;
+        }
+
+        emit!(ProtocolFeeCollected {
+            mint: vault.token_mint,
+            recipient: recipient.owner,
+            weight_bps: recipient.weight_bps,
+            amount: share
+        });
+    }
 
     // Reset the protocol yield in the vault after collection
     vault.protocol_yield -= protocol_yield;
 
-    // Log the collection for transparency
-    msg!("Collect {{mint: {}, amount: {}}}", vault.token_mint.key(), protocol_yield);
-
     Ok(())
 }
 
 /// Accounts context for the `collect` instruction
 #[derive(Accounts)]
 pub struct CollectInstructionAccounts<'info> {
-    /// The signer of the transaction (must be admin)
-    #[account(mut)]
+    /// The signer of the transaction (anyone)
     pub signer: Signer<'info>,
 
     /// The vault token mint
     /// CHECK: no constraints, assumed valid
     pub vault_mint: Account<'info, Mint>,
 
-    /// The admin's token account for receiving protocol yield
-    #[account(mut, token::authority = signer, token::mint = vault_mint)]
-    pub signer_ata: Account<'info, TokenAccount>,
+    /// The FeeConfig PDA listing the fee recipients
+    #[account(seeds = [FEE_CONFIG_SEED.as_bytes()], bump)]
+    pub fee_config: Account<'info, FeeConfig>,
 
     /// The Vault PDA storing vault state
     #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
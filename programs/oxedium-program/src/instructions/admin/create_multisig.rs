@@ -0,0 +1,91 @@
+use crate::{components::{check_admin, check_multisig_config}, states::{Multisig, Treasury}, utils::{MULTISIG_SEED, MULTISIG_SIGNER_SEED, OXEDIUM_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+
+/// Create the M-of-N multisig that can govern the treasury
+///
+/// The multisig acts through its signer PDA (`MULTISIG_SIGNER_SEED`), which executes
+/// approved proposals. To hand it the protocol, propose the signer PDA as admin and
+/// accept through a proposal, or grant it roles. The signer PDA pays rent for accounts
+/// it creates, so fund it with SOL before listing vaults.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `signers` - keys allowed to propose and approve (at most `MAX_MULTISIG_SIGNERS`)
+/// * `threshold` - approvals needed to execute a proposal
+pub fn create_multisig(
+    ctx: Context<CreateMultisigInstructionAccounts>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    // Ensure the signer is the treasury admin
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    check_multisig_config(&signers, threshold)?;
+
+    let multisig: &mut Account<'_, Multisig> = &mut ctx.accounts.multisig_pda;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.signer_bump = ctx.bumps.multisig_signer;
+    multisig.owner_set_seqno = 0;
+
+    msg!("CreateMultisig {{authority: {}, signers: {:?}, threshold: {}}}",
+        ctx.accounts.multisig_signer.key(),
+        multisig.signers,
+        multisig.threshold
+    );
+
+    Ok(())
+}
+
+/// Accounts context for the `create_multisig` instruction
+#[derive(Accounts)]
+pub struct CreateMultisigInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Multisig PDA account
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    /// `space` (8 + 4 + 32 * 10 + 1 + 1 + 4 = 338 bytes):
+    /// - 8 bytes: Anchor account discriminator
+    /// - 4 + 32 * `MAX_MULTISIG_SIGNERS` bytes: `signers` Vec<Pubkey>
+    /// - 1 byte: `threshold` u8
+    /// - 1 byte: `signer_bump` u8
+    /// - 4 bytes: `owner_set_seqno` u32 — bumped on every signer change
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MULTISIG_SEED.as_bytes()],
+        bump,
+        space = 8 + 4 + 32 * 10 + 1 + 1 + 4,
+    )]
+    pub multisig_pda: Account<'info, Multisig>,
+
+    /// The multisig signer PDA that executes approved proposals
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SIGNER_SEED`
+    /// - multisig_pda key
+    ///
+    /// `bump` is automatically derived
+    /// CHECK: data-less PDA, only used as a signer
+    #[account(seeds = [MULTISIG_SIGNER_SEED.as_bytes(), multisig_pda.key().as_ref()], bump)]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    /// The Treasury PDA account used for admin checks
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// System program (required to create the multisig)
+    pub system_program: Program<'info, System>,
+}
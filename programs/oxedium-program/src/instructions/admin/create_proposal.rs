@@ -0,0 +1,88 @@
+use crate::{components::{is_whitelisted_action, multisig_signer_index}, states::{Multisig, Proposal, ProposalAccount}, utils::{OxediumError, MAX_MULTISIG_SIGNERS, MULTISIG_SEED, PROPOSAL_SEED}};
+use anchor_lang::prelude::*;
+
+/// Propose an admin action to the multisig
+///
+/// The proposal stores the full instruction (data and account metas) so approvers sign
+/// off on exactly what will run. The proposer's approval is recorded immediately.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `id` - caller-chosen identifier, used as the seed of the proposal PDA
+/// * `data` - instruction data of a whitelisted oxedium instruction
+/// * `accounts` - account metas of that instruction
+pub fn create_proposal(
+    ctx: Context<CreateProposalInstructionAccounts>,
+    id: u64,
+    data: Vec<u8>,
+    accounts: Vec<ProposalAccount>,
+) -> Result<()> {
+    let multisig: &Account<'_, Multisig> = &ctx.accounts.multisig_pda;
+    let proposer_index = multisig_signer_index(multisig, &ctx.accounts.signer.key())?;
+
+    if !is_whitelisted_action(&data) {
+        return Err(OxediumError::ActionNotWhitelisted.into());
+    }
+
+    let mut approvals = vec![false; multisig.signers.len()];
+    approvals[proposer_index] = true;
+
+    let proposal: &mut Account<'_, Proposal> = &mut ctx.accounts.proposal_pda;
+    proposal.id = id;
+    proposal.proposer = ctx.accounts.signer.key();
+    proposal.data = data;
+    proposal.accounts = accounts;
+    proposal.approvals = approvals;
+    proposal.owner_set_seqno = multisig.owner_set_seqno;
+    proposal.executed = false;
+
+    msg!("CreateProposal {{id: {}, proposer: {}}}", proposal.id, proposal.proposer);
+
+    Ok(())
+}
+
+/// Accounts context for the `create_proposal` instruction
+#[derive(Accounts)]
+#[instruction(id: u64, data: Vec<u8>, accounts: Vec<ProposalAccount>)]
+pub struct CreateProposalInstructionAccounts<'info> {
+    /// The signer of the transaction (must be a multisig signer); pays for the proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Multisig PDA account
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [MULTISIG_SEED.as_bytes()], bump)]
+    pub multisig_pda: Account<'info, Multisig>,
+
+    /// The Proposal PDA account
+    ///
+    /// Seeds:
+    /// - `PROPOSAL_SEED`
+    /// - `id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    /// `space`:
+    /// - 8 bytes: Anchor account discriminator
+    /// - 8 bytes: `id` u64
+    /// - 32 bytes: `proposer` Pubkey
+    /// - 4 + data length bytes: `data` Vec<u8>
+    /// - 4 + 34 * accounts length bytes: `accounts` Vec<ProposalAccount>
+    /// - 4 + `MAX_MULTISIG_SIGNERS` bytes: `approvals` Vec<bool>
+    /// - 4 bytes: `owner_set_seqno` u32
+    /// - 1 byte: `executed` bool
+    #[account(
+        init,
+        payer = signer,
+        seeds = [PROPOSAL_SEED.as_bytes(), id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + 8 + 32 + 4 + data.len() + 4 + 34 * accounts.len() + 4 + MAX_MULTISIG_SIGNERS + 4 + 1,
+    )]
+    pub proposal_pda: Account<'info, Proposal>,
+
+    /// System program (required to create the proposal)
+    pub system_program: Program<'info, System>,
+}
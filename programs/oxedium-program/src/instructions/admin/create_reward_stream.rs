@@ -0,0 +1,117 @@
+use crate::{components::check_admin, states::{RewardStream, Treasury, Vault}, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Create a liquidity mining reward stream for a vault and its funding escrow
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `emission_per_second` - amount of reward tokens emitted per second to all LPs of the vault
+/// * `start_time` - unix timestamp when emissions start
+/// * `end_time` - unix timestamp when emissions end
+pub fn create_reward_stream(
+    ctx: Context<CreateRewardStreamInstructionAccounts>,
+    emission_per_second: u64,
+    start_time: i64,
+    end_time: i64,
+) -> Result<()> {
+    // Ensure the caller is an admin using the Treasury account
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+    require!(start_time < end_time && current_timestamp < end_time, OxediumError::InvalidRewardSchedule);
+
+    // Initialize the RewardStream PDA
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+
+    stream.vault = ctx.accounts.vault_pda.key();              // vault whose LPs earn the rewards
+    stream.reward_mint = ctx.accounts.reward_mint.key();      // partner token paid as reward
+    stream.emission_per_second = emission_per_second;         // reward tokens emitted per second
+    stream.start_time = start_time;                           // emissions start
+    stream.end_time = end_time;                               // emissions end
+    stream.last_update_time = start_time;                     // nothing accrued before the start
+    stream.cumulative_reward_per_lp = 0;                      // cumulative reward per LP token
+    stream.funded_amount = 0;                                 // funded through `fund_reward_stream`
+    stream.emitted_amount = 0;                                // rewards accrued to LPs so far
+
+    // From now on every LP balance change must settle the stream
+    ctx.accounts.vault_pda.has_reward_stream = true;
+
+    // Log the stream for transparency
+    msg!("CreateRewardStream {{mint: {}, reward_mint: {}, emission_per_second: {}, start_time: {}, end_time: {}}}",
+        ctx.accounts.vault_mint.key(),
+        stream.reward_mint,
+        stream.emission_per_second,
+        stream.start_time,
+        stream.end_time
+    );
+
+    Ok(())
+}
+
+/// Accounts context for the `create_reward_stream` instruction
+#[derive(Accounts)]
+pub struct CreateRewardStreamInstructionAccounts<'info> {
+    /// The signer of the transaction (must be admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The reward token mint
+    pub reward_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The RewardStream PDA account
+    ///
+    /// Seeds:
+    /// - `REWARD_STREAM_SEED`
+    /// - `vault_pda` key
+    ///
+    /// Space:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: vault
+    /// - 32 bytes: reward_mint
+    /// - 8 bytes: emission_per_second
+    /// - 8 bytes: start_time
+    /// - 8 bytes: end_time
+    /// - 8 bytes: last_update_time
+    /// - 16 bytes: cumulative_reward_per_lp
+    /// - 8 bytes: funded_amount
+    /// - 8 bytes: emitted_amount
+    #[account(
+        init,
+        payer = signer,
+        seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 16 + 8 + 8,
+    )]
+    pub reward_stream: Account<'info, RewardStream>,
+
+    /// Escrow token account holding the stream funding
+    ///
+    /// The Treasury PDA is the authority so rewards are paid with the same signer as yield.
+    #[account(
+        init,
+        payer = signer,
+        seeds = [REWARD_ESCROW_SEED.as_bytes(), reward_stream.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = treasury_pda,
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    /// The Treasury PDA account used for admin checks and escrow authority
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// Token program required to create the escrow
+    pub token_program: Program<'info, Token>,
+
+    /// System program required to create accounts
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,95 @@
+use crate::{components::{apply_treasury_params, apply_vault_params, check_treasury_params, check_vault_params}, events::{ChangeExecutedEvent, TimelockDelayUpdated}, states::{ParameterChange, QueuedChange, Treasury, Vault}, utils::{OxediumError, OXEDIUM_SEED, QUEUED_CHANGE_SEED, TREASURY_SEED, VAULT_SEED}};
+use anchor_lang::prelude::*;
+
+/// Execute a queued parameter change once its timelock has elapsed
+///
+/// Permissionless: anyone can execute a matured change. The queued change is closed
+/// and its rent returned to the proposer.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn execute_change(ctx: Context<ExecuteChangeInstructionAccounts>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let queued: &Account<'_, QueuedChange> = &ctx.accounts.queued_change;
+
+    require!(now >= queued.eta, OxediumError::TimelockNotElapsed);
+
+    match queued.change {
+        ParameterChange::Treasury { fee_bps, deviation } => {
+            // Bounds may have been tightened since the change was queued
+            check_treasury_params(&ctx.accounts.treasury_pda, fee_bps, deviation)?;
+            apply_treasury_params(&mut ctx.accounts.treasury_pda, fee_bps, deviation);
+        }
+        ParameterChange::Vault { vault_mint, params } => {
+            let vault: &mut Account<'_, Vault> = ctx.accounts.vault_pda.as_mut()
+                .ok_or(OxediumError::InvalidQueuedChange)?;
+
+            // The vault must be the PDA of the mint the change was queued for
+            let (expected_vault, _) = Pubkey::find_program_address(
+                &[VAULT_SEED.as_bytes(), vault_mint.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(vault.key(), expected_vault, OxediumError::InvalidQueuedChange);
+
+            check_vault_params(&ctx.accounts.treasury_pda, &params)?;
+            apply_vault_params(vault, &params, now);
+        }
+        ParameterChange::TimelockDelay { delay } => {
+            let old_delay = std::mem::replace(&mut ctx.accounts.treasury_pda.timelock_delay, delay);
+            emit!(TimelockDelayUpdated {
+                old_delay,
+                delay
+            });
+        }
+    }
+
+    emit!(ChangeExecutedEvent {
+        id: queued.id,
+        executor: ctx.accounts.signer.key(),
+        change: queued.change
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `execute_change` instruction
+#[derive(Accounts)]
+pub struct ExecuteChangeInstructionAccounts<'info> {
+    /// The signer of the transaction (anyone)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The proposer of the change, receives the rent of the closed queued change
+    /// CHECK: constrained to the proposer recorded in `queued_change`
+    #[account(mut, address = queued_change.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// The queued change PDA, closed after execution
+    ///
+    /// Seeds:
+    /// - `QUEUED_CHANGE_SEED`
+    /// - `queued_change.id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    #[account(
+        mut,
+        seeds = [QUEUED_CHANGE_SEED.as_bytes(), queued_change.id.to_le_bytes().as_ref()],
+        bump,
+        close = proposer,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// The Vault PDA account, required for vault changes
+    #[account(mut)]
+    pub vault_pda: Option<Account<'info, Vault>>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,94 @@
+use crate::{components::{count_approvals, is_whitelisted_action}, program::OxediumProgram, states::{Multisig, Proposal}, utils::{OxediumError, MULTISIG_SEED, MULTISIG_SIGNER_SEED, PROPOSAL_SEED}};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed};
+
+/// Execute a multisig proposal that reached its approval threshold
+///
+/// The stored instruction is invoked on this program with the multisig signer PDA as
+/// signer. `remaining_accounts` must hold the proposal's accounts in the stored order.
+/// Anyone may execute once the threshold is met. The executed proposal is closed and its
+/// rent refunded to the proposer.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposalInstructionAccounts<'info>>) -> Result<()> {
+    let multisig: &Account<'_, Multisig> = &ctx.accounts.multisig_pda;
+    let proposal: &mut Account<'_, Proposal> = &mut ctx.accounts.proposal_pda;
+
+    require!(proposal.owner_set_seqno == multisig.owner_set_seqno, OxediumError::StaleProposal);
+    require!(!proposal.executed, OxediumError::ProposalAlreadyExecuted);
+    require!(count_approvals(&proposal.approvals) >= multisig.threshold as usize, OxediumError::NotEnoughApprovals);
+
+    // Re-check in case the whitelist shrank since the proposal was created
+    if !is_whitelisted_action(&proposal.data) {
+        return Err(OxediumError::ActionNotWhitelisted.into());
+    }
+
+    let remaining = ctx.remaining_accounts;
+
+    if remaining.len() != proposal.accounts.len()
+        || remaining.iter().zip(proposal.accounts.iter()).any(|(info, meta)| info.key() != meta.pubkey)
+    {
+        return Err(OxediumError::InvalidRemainingAccounts.into());
+    }
+
+    // Mark executed before the CPI so the proposal cannot be replayed
+    proposal.executed = true;
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal.accounts.iter()
+            .map(|meta| AccountMeta { pubkey: meta.pubkey, is_signer: meta.is_signer, is_writable: meta.is_writable })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+
+    let multisig_key = multisig.key();
+    let seeds = &[MULTISIG_SIGNER_SEED.as_bytes(), multisig_key.as_ref(), &[multisig.signer_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let mut account_infos = remaining.to_vec();
+    account_infos.push(ctx.accounts.oxedium_program.to_account_info());
+
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+    msg!("ExecuteProposal {{id: {}, executor: {}}}", proposal.id, ctx.accounts.signer.key());
+
+    Ok(())
+}
+
+/// Accounts context for the `execute_proposal` instruction
+#[derive(Accounts)]
+pub struct ExecuteProposalInstructionAccounts<'info> {
+    /// The signer of the transaction (anyone)
+    pub signer: Signer<'info>,
+
+    /// CHECK: constrained to the proposer recorded in `proposal_pda`; receives its rent
+    #[account(mut, address = proposal_pda.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// The Multisig PDA account
+    ///
+    /// Not writable: the executed instruction may itself change the multisig.
+    ///
+    /// Seeds:
+    /// - `MULTISIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [MULTISIG_SEED.as_bytes()], bump)]
+    pub multisig_pda: Account<'info, Multisig>,
+
+    /// The Proposal PDA account
+    ///
+    /// Seeds:
+    /// - `PROPOSAL_SEED`
+    /// - `proposal_pda.id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    /// Closed after execution, with rent going to the proposer
+    #[account(mut, close = proposer, seeds = [PROPOSAL_SEED.as_bytes(), proposal_pda.id.to_le_bytes().as_ref()], bump)]
+    pub proposal_pda: Account<'info, Proposal>,
+
+    /// This program, invoked with the proposal's instruction
+    pub oxedium_program: Program<'info, OxediumProgram>,
+}
@@ -0,0 +1,69 @@
+use crate::{components::{check_admin, update_reward_stream}, states::{RewardStream, Treasury, Vault}, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+/// Extend a reward stream and/or change its emission rate going forward
+///
+/// An ended stream restarts from the current time, nothing is emitted retroactively.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `end_time` - new unix timestamp when emissions end (cannot be earlier than the current one)
+/// * `emission_per_second` - new amount of reward tokens emitted per second
+pub fn extend_reward_stream(
+    ctx: Context<ExtendRewardStreamInstructionAccounts>,
+    end_time: i64,
+    emission_per_second: u64,
+) -> Result<()> {
+    // Ensure the caller is an admin using the Treasury account
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    require!(end_time > current_timestamp && end_time >= stream.end_time, OxediumError::InvalidRewardSchedule);
+
+    // Accrue at the old rate up to now
+    update_reward_stream(stream, ctx.accounts.vault_pda.initial_liquidity, current_timestamp)?;
+
+    // Restart an ended stream from now
+    if stream.end_time <= current_timestamp {
+        stream.start_time = current_timestamp;
+        stream.last_update_time = current_timestamp;
+    }
+
+    stream.end_time = end_time;
+    stream.emission_per_second = emission_per_second;
+
+    // Log the update for transparency
+    msg!("ExtendRewardStream {{mint: {}, emission_per_second: {}, end_time: {}}}",
+        ctx.accounts.vault_mint.key(),
+        stream.emission_per_second,
+        stream.end_time
+    );
+
+    Ok(())
+}
+
+/// Accounts context for the `extend_reward_stream` instruction
+#[derive(Accounts)]
+pub struct ExtendRewardStreamInstructionAccounts<'info> {
+    /// The signer of the transaction (must be admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The RewardStream PDA account
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Account<'info, RewardStream>,
+
+    /// The Treasury PDA account used for admin checks
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
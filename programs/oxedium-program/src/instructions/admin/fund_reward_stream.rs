@@ -0,0 +1,71 @@
+use crate::{components::{check_admin, update_reward_stream}, states::{RewardStream, Treasury, Vault}, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+/// Deposit reward tokens into a reward stream escrow
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `amount` - amount of reward tokens to deposit
+pub fn fund_reward_stream(ctx: Context<FundRewardStreamInstructionAccounts>, amount: u64) -> Result<()> {
+    // Ensure the caller is an admin using the Treasury account
+    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+
+    // Accrue with the previous funding first, so new funds only cover future emissions
+    update_reward_stream(stream, ctx.accounts.vault_pda.initial_liquidity, Clock::get()?.unix_timestamp)?;
+
+    // Transfer reward tokens from admin to escrow
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.signer_reward_ata.to_account_info(),
+        to: ctx.accounts.reward_escrow.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    stream.funded_amount += amount;
+
+    // Log the funding for transparency
+    msg!("FundRewardStream {{mint: {}, amount: {}, funded: {}}}",
+        ctx.accounts.vault_mint.key(),
+        amount,
+        stream.funded_amount
+    );
+
+    Ok(())
+}
+
+/// Accounts context for the `fund_reward_stream` instruction
+#[derive(Accounts)]
+pub struct FundRewardStreamInstructionAccounts<'info> {
+    /// The signer of the transaction (must be admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The admin's reward token account
+    #[account(mut, token::authority = signer, token::mint = reward_stream.reward_mint)]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    /// The Vault PDA account
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The RewardStream PDA account
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Account<'info, RewardStream>,
+
+    /// Escrow token account holding the stream funding
+    #[account(mut, seeds = [REWARD_ESCROW_SEED.as_bytes(), reward_stream.key().as_ref()], bump)]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    /// The Treasury PDA account used for admin checks
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// Token program required to execute token transfers
+    pub token_program: Program<'info, Token>,
+}
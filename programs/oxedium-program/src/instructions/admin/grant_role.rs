@@ -0,0 +1,43 @@
+use crate::{components::{check_admin, set_role_holder}, events::RoleChanged, states::{Role, Treasury}, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Grant an operational role to a key, replacing its current holder
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `role` - role to assign
+/// * `holder` - public key that will hold the role
+pub fn grant_role(ctx: Context<GrantRoleInstructionAccounts>, role: Role, holder: Pubkey) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the treasury admin
+    check_admin(treasury, &ctx.accounts.signer)?;
+
+    let previous_holder = set_role_holder(treasury, role, holder);
+
+    emit!(RoleChanged {
+        role,
+        previous_holder,
+        holder
+    });
+
+    Ok(())
+}
+
+/// Accounts context for `grant_role` instruction
+#[derive(Accounts)]
+pub struct GrantRoleInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -1,34 +1,60 @@
-use crate::{states::Treasury, utils::{TREASURY_SEED, OXEDIUM_SEED, OxediumError}};
+use crate::{components::check_treasury_params, events::{AdminChanged, Paused, TreasuryUpdated}, program::OxediumProgram, states::Treasury, utils::*};
 use anchor_lang::prelude::*;
-use std::str::FromStr;
 
 /// Initialize the Treasury account (PDA)
 ///
+/// Only the program's upgrade authority (read from its ProgramData account) can
+/// initialize the treasury, so every deployment is bootstrapped by whoever deployed it.
+///
 /// # Arguments
 /// * `ctx` - context containing all accounts required for this instruction
-/// * `protocol_fee_bps` - protocol fee in basis points (bps), taken from the total swap fee
+/// * `protocol_fee_bps` - protocol fee in basis points (bps), taken from the total swap fee;
+///   must be <= `DEFAULT_MAX_PROTOCOL_FEE_BPS`
+/// * `deviation` - liquidity threshold divisor (e.g., 10 → 10% of current liquidity);
+///   must be >= `DEFAULT_MIN_DEVIATION`
+/// * `stoptap` - whether the protocol starts paused
 pub fn init_treasury(
     ctx: Context<InitTreasuryInstructionAccounts>,
-    protocol_fee_bps: u64
+    protocol_fee_bps: u64,
+    deviation: u64,
+    stoptap: bool,
 ) -> Result<()> {
-    // Define the admin public key (hardcoded)
-    // For production, it is safer to store this in a config PDA
-    let admin_key = Pubkey::from_str("3gXnk9LTHHtFzKK5pkKzp58okeo9V72MjGSyzFUCvKk2")
-        .map_err(|_| OxediumError::InvalidAdmin)?; // ensure the key is valid
-
-    // Check if the signer is the admin
-    if ctx.accounts.signer.key() != admin_key {
-        return Err(OxediumError::InvalidAdmin.into());
-    }
-
     // Get a mutable reference to the treasury PDA
     let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
 
+    // Start with the default parameter bounds
+    treasury.max_base_fee = DEFAULT_MAX_BASE_FEE;
+    treasury.max_protocol_fee_bps = DEFAULT_MAX_PROTOCOL_FEE_BPS;
+    treasury.max_oracle_age = DEFAULT_MAX_ORACLE_AGE;
+    treasury.min_deviation = DEFAULT_MIN_DEVIATION;
+
+    check_treasury_params(treasury, protocol_fee_bps, deviation)?;
+
     // Set the treasury fields
     treasury.admin = ctx.accounts.signer.key();  // admin public key
-    treasury.stoptap = false;                  // stop-tap flag, default false
+    treasury.stoptap = stoptap;                // stop-tap flag
     treasury.fee_bps = protocol_fee_bps;     // protocol fee in basis points
-    treasury.deviation = 10;                // 10%
+    treasury.deviation = deviation;         // max swap share of the vault
+    treasury.pending_admin = Pubkey::default(); // no admin handover in progress
+    treasury.timelock_delay = 0;               // changes apply instantly until a delay is queued
+    treasury.version = TREASURY_VERSION;       // current account layout
+    // role holders stay unset (default key) until granted; the admin holds every role
+
+    // Initial state, reported as changes from an empty treasury
+    emit!(AdminChanged {
+        previous_admin: Pubkey::default(),
+        admin: treasury.admin
+    });
+    emit!(TreasuryUpdated {
+        old_fee_bps: 0,
+        fee_bps: protocol_fee_bps,
+        old_deviation: 0,
+        deviation
+    });
+    emit!(Paused {
+        signer: treasury.admin,
+        stoptap
+    });
 
     Ok(())
 }
@@ -36,29 +62,33 @@ pub fn init_treasury(
 /// Accounts context for the `init_treasury` instruction
 #[derive(Accounts)]
 pub struct InitTreasuryInstructionAccounts<'info> {
-    /// The signer of the transaction
+    /// The signer of the transaction (must be the program's upgrade authority)
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    /// This program, used to locate its ProgramData account
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ OxediumError::InvalidAdmin)]
+    pub program: Program<'info, OxediumProgram>,
+
+    /// The ProgramData account holding the upgrade authority
+    #[account(constraint = program_data.upgrade_authority_address == Some(signer.key()) @ OxediumError::InvalidAdmin)]
+    pub program_data: Account<'info, ProgramData>,
+
     /// The Treasury PDA account
     ///
     /// This account will be created and initialized when this instruction is called.
     /// Seeds:
     /// - `OXEDIUM_SEED` (for uniqueness)
     /// - `TREASURY_SEED` (for uniqueness)
+    ///
     /// `bump` is automatically calculated by Anchor
-    /// `space` is the total account size (8 + 1 + 32 + 8 = 41 bytes):
-    /// - 8 bytes: Anchor account discriminator
-    /// - 1 byte: bool `stoptap`
-    /// - 32 bytes: `admin` Pubkey
-    /// - 8 bytes: `proto_fee_bps` u64 — protocol fee in basis points, taken from the total swap fee
-    /// - 8 bytes: `deviation` u64 — max swap %
+    /// `space` is the discriminator plus `Treasury::INIT_SPACE`
     #[account(
         init,
         payer = signer,
         seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()],
         bump,
-        space = 8 + 1 + 32 + 8 + 8,
+        space = 8 + Treasury::INIT_SPACE,
     )]
     pub treasury_pda: Account<'info, Treasury>,
 
@@ -1,4 +1,4 @@
-use crate::{components::check_admin, states::{Vault, Treasury}, utils::*};
+use crate::{components::{check_role, check_vault_bounds}, events::VaultInitialized, states::{Role, Vault, VaultStatus, Treasury}, utils::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
@@ -14,8 +14,10 @@ pub fn init_vault(
     base_fee: u64,
     max_age_price: u64,
 ) -> Result<()> {
-    // Ensure the caller is an admin using the Treasury account
-    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+    // Ensure the caller is the vault lister (or admin)
+    check_role(&ctx.accounts.treasury_pda, Role::VaultLister, &ctx.accounts.signer.key())?;
+
+    check_vault_bounds(&ctx.accounts.treasury_pda, base_fee, max_age_price)?;
 
     // Initialize the Vault PDA
     let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
@@ -29,6 +31,33 @@ pub fn init_vault(
     vault.current_liquidity = 0;                             // current liquidity in the vault
     vault.cumulative_yield_per_lp = 0;                       // cumulative yield per LP token
     vault.protocol_yield = 0;                                // yield earned by the protocol
+    vault.has_reward_stream = false;                         // no liquidity mining rewards yet
+    vault.total_locked = 0;                                  // LP locked for boosted yield
+    vault.total_boost_weight = 0;                            // extra LP weight of boosted locks
+    vault.cumulative_penalty_per_lp = 0;                     // early-unlock penalties per liquid LP token
+    vault.min_stake_age = 0;                                 // yield vests immediately by default
+    vault.yield_drip_window = 0;                             // fees are credited immediately by default
+    vault.undripped_yield = 0;                               // fees waiting to be dripped to stakers
+    vault.last_drip_time = Clock::get()?.unix_timestamp;     // start of the drip schedule
+    vault.drip_end_time = vault.last_drip_time;              // nothing buffered yet
+    vault.unclaimed_yield = 0;                               // yield credited to stakers, not yet paid out
+    vault.open_stakers = 0;                                  // Staker accounts not closed yet
+    vault.max_total_deposits = 0;                            // no vault deposit cap by default
+    vault.max_deposit_per_user = 0;                          // no per-user deposit cap by default
+    vault.caps_in_usd = false;                               // caps are in token units
+    vault.allowlist_enabled = false;                         // open to every wallet
+    vault.allowlist_root = [0; 32];                          // no allowlist yet
+    vault.status = VaultStatus::Active;                      // open for stakes and swaps
+    vault.version = VAULT_VERSION;                           // current account layout
+
+    emit!(VaultInitialized {
+        lister: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        lp_mint: vault.lp_mint,
+        pyth_price_account: vault.pyth_price_account,
+        base_fee,
+        max_age_price
+    });
 
     Ok(())
 }
@@ -36,7 +65,7 @@ pub fn init_vault(
 /// Accounts context for the `init_vault` instruction
 #[derive(Accounts)]
 pub struct InitVaultInstructionAccounts<'info> {
-    /// The signer of the transaction (must be admin)
+    /// The signer of the transaction (must be the vault lister or admin)
     #[account(mut)]
     pub signer: Signer<'info>,
 
@@ -49,7 +78,8 @@ pub struct InitVaultInstructionAccounts<'info> {
 
     /// The LP token mint for liquidity providers
     ///
-    /// This mint is created when initializing the vault.
+    /// This mint is created when initializing the vault. It outlives a closed vault (legacy
+    /// SPL mints cannot be closed), so a token whose vault was closed cannot be listed again.
     /// The Treasury PDA is set as both the mint authority and freeze authority.
     #[account(
         init,
@@ -68,28 +98,20 @@ pub struct InitVaultInstructionAccounts<'info> {
     /// Seeds:
     /// - `VAULT_SEED`
     /// - `vault_mint` key
-    /// Space:
-    /// - 8 bytes: Anchor discriminator
-    /// - 32 bytes: token_mint
-    /// - 32 bytes: pyth_price_account
-    /// - 8 bytes: max_age_price
-    /// - 32 bytes: lp_mint
-    /// - 8 bytes: initial_liquidity
-    /// - 8 bytes: current_liquidity
-    /// - 16 bytes: cumulative_yield_per_lp
-    /// - 8 bytes: protocol_yield
+    ///
+    /// `space` is the discriminator plus `Vault::INIT_SPACE`
     #[account(
         init,
         payer = signer,
         seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()],
         bump,
-        space = 8 + 8 + 32 + 32 + 8 + 32 + 8 + 8 + 16 + 8,
+        space = 8 + Vault::INIT_SPACE,
     )]
     pub vault_pda: Account<'info, Vault>,
 
     /// The Treasury PDA account
     ///
-    /// Must be mutable and is used for admin checks and LP mint authority
+    /// Must be mutable and is used for role checks and LP mint authority
     #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury_pda: Account<'info, Treasury>,
 
@@ -0,0 +1,62 @@
+use crate::{components::{upgrade_staker, write_upgraded_account}, states::{Staker, Vault}, utils::{OxediumError, POSITION_SEED, STAKER_SEED}};
+use anchor_lang::prelude::*;
+
+/// Upgrade a Staker account (regular or NFT position) to the current layout
+///
+/// Permissionless: the upgrade is deterministic and the signer only pays the extra rent.
+/// The account must derive from `vault_pda` and `owner`; it is counted in the vault's
+/// `open_stakers`, which older layouts did not track.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn migrate_staker(ctx: Context<MigrateStakerInstructionAccounts>) -> Result<()> {
+    let vault_key = ctx.accounts.vault_pda.key();
+    let owner_key = ctx.accounts.owner.key();
+
+    let belongs_to_vault = [STAKER_SEED, POSITION_SEED].iter().any(|seed| {
+        Pubkey::find_program_address(&[seed.as_bytes(), vault_key.as_ref(), owner_key.as_ref()], &crate::ID).0
+            == ctx.accounts.staker_pda.key()
+    });
+    require!(belongs_to_vault, OxediumError::InvalidStakerAccount);
+
+    let account = ctx.accounts.staker_pda.to_account_info();
+    let staker = upgrade_staker(&account.try_borrow_data()?)?;
+
+    write_upgraded_account(
+        &account,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Staker::INIT_SPACE,
+        &staker,
+    )?;
+
+    ctx.accounts.vault_pda.open_stakers += 1;
+
+    msg!("MigrateStaker {{owner: {}, version: {}}}", staker.owner, staker.version);
+
+    Ok(())
+}
+
+/// Accounts context for the `migrate_staker` instruction
+#[derive(Accounts)]
+pub struct MigrateStakerInstructionAccounts<'info> {
+    /// The signer of the transaction, pays the extra rent
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The staker's owner, or the position NFT mint for positions
+    /// CHECK: only used to derive the Staker PDA
+    pub owner: UncheckedAccount<'info>,
+
+    /// The Vault PDA the Staker account belongs to, already in the current layout
+    #[account(mut)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The Staker account in an older layout
+    /// CHECK: owner is checked here, the discriminator in `upgrade_staker`
+    #[account(mut, owner = crate::ID)]
+    pub staker_pda: UncheckedAccount<'info>,
+
+    /// System program (required for the rent top-up)
+    pub system_program: Program<'info, System>,
+}
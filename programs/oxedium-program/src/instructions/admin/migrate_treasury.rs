@@ -0,0 +1,47 @@
+use crate::{components::{upgrade_treasury, write_upgraded_account}, states::Treasury, utils::{OXEDIUM_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+
+/// Upgrade the Treasury account to the current layout
+///
+/// Permissionless: the upgrade is deterministic and the signer only pays the extra rent.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn migrate_treasury(ctx: Context<MigrateTreasuryInstructionAccounts>) -> Result<()> {
+    let account = ctx.accounts.treasury_pda.to_account_info();
+    let treasury = upgrade_treasury(&account.try_borrow_data()?)?;
+
+    write_upgraded_account(
+        &account,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Treasury::INIT_SPACE,
+        &treasury,
+    )?;
+
+    msg!("MigrateTreasury {{version: {}}}", treasury.version);
+
+    Ok(())
+}
+
+/// Accounts context for the `migrate_treasury` instruction
+#[derive(Accounts)]
+pub struct MigrateTreasuryInstructionAccounts<'info> {
+    /// The signer of the transaction, pays the extra rent
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account in an older layout
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    /// CHECK: owner and seeds are checked here, the discriminator in `upgrade_treasury`
+    #[account(mut, owner = crate::ID, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: UncheckedAccount<'info>,
+
+    /// System program (required for the rent top-up)
+    pub system_program: Program<'info, System>,
+}
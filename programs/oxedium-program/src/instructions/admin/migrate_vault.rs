@@ -0,0 +1,51 @@
+use crate::{components::{upgrade_vault, write_upgraded_account}, states::Vault, utils::VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+/// Upgrade a Vault account to the current layout
+///
+/// Permissionless: the upgrade is deterministic and the signer only pays the extra rent.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+pub fn migrate_vault(ctx: Context<MigrateVaultInstructionAccounts>) -> Result<()> {
+    let account = ctx.accounts.vault_pda.to_account_info();
+    let vault = upgrade_vault(&account.try_borrow_data()?)?;
+
+    write_upgraded_account(
+        &account,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Vault::INIT_SPACE,
+        &vault,
+    )?;
+
+    msg!("MigrateVault {{mint: {}, version: {}}}", vault.token_mint, vault.version);
+
+    Ok(())
+}
+
+/// Accounts context for the `migrate_vault` instruction
+#[derive(Accounts)]
+pub struct MigrateVaultInstructionAccounts<'info> {
+    /// The signer of the transaction, pays the extra rent
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account in an older layout
+    ///
+    /// Seeds:
+    /// - `VAULT_SEED`
+    /// - vault_mint key
+    ///
+    /// `bump` is automatically derived
+    /// CHECK: owner and seeds are checked here, the discriminator in `upgrade_vault`
+    #[account(mut, owner = crate::ID, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: UncheckedAccount<'info>,
+
+    /// System program (required for the rent top-up)
+    pub system_program: Program<'info, System>,
+}
@@ -3,9 +3,61 @@ pub use update_treasury::*;
 pub use init_vault::*;
 pub use update_vault::*;
 pub use collect::*;
+pub use create_reward_stream::*;
+pub use fund_reward_stream::*;
+pub use extend_reward_stream::*;
+pub use cancel_reward_stream::*;
+pub use set_vault_allowlist::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use cancel_admin_proposal::*;
+pub use set_stoptap::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use queue_change::*;
+pub use execute_change::*;
+pub use cancel_change::*;
+pub use create_multisig::*;
+pub use change_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use set_fee_config::*;
+pub use set_parameter_bounds::*;
+pub use set_vault_status::*;
+pub use close_vault::*;
+pub use migrate_treasury::*;
+pub use migrate_vault::*;
+pub use migrate_staker::*;
 
 pub mod init_treasury;
 pub mod update_treasury;
 pub mod init_vault;
 pub mod update_vault;
-pub mod collect;
\ No newline at end of file
+pub mod collect;
+pub mod create_reward_stream;
+pub mod fund_reward_stream;
+pub mod extend_reward_stream;
+pub mod cancel_reward_stream;
+pub mod set_vault_allowlist;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod cancel_admin_proposal;
+pub mod set_stoptap;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod queue_change;
+pub mod execute_change;
+pub mod cancel_change;
+pub mod create_multisig;
+pub mod change_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod set_fee_config;
+pub mod set_parameter_bounds;
+pub mod set_vault_status;
+pub mod close_vault;
+pub mod migrate_treasury;
+pub mod migrate_vault;
+pub mod migrate_staker;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use crate::{components::check_admin, events::AdminProposed, states::Treasury, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Propose a new treasury admin (step 1 of the admin handover)
+///
+/// The current admin stays in control until `new_admin` signs `accept_admin`.
+/// Proposing again overwrites any earlier pending admin.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `new_admin` - public key that will be allowed to accept the admin role
+pub fn propose_admin(
+    ctx: Context<ProposeAdminInstructionAccounts>,
+    new_admin: Pubkey
+) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the treasury admin
+    check_admin(treasury, &ctx.accounts.signer)?;
+
+    treasury.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: treasury.admin,
+        pending_admin: treasury.pending_admin
+    });
+
+    Ok(())
+}
+
+/// Accounts context for `propose_admin` instruction
+#[derive(Accounts)]
+pub struct ProposeAdminInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
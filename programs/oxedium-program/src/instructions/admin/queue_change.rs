@@ -0,0 +1,107 @@
+use crate::{components::{check_admin, check_role, check_treasury_params, check_vault_params}, events::ChangeQueuedEvent, states::{ParameterChange, QueuedChange, Role, Treasury}, utils::{OxediumError, OXEDIUM_SEED, QUEUED_CHANGE_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+/// Schedule a parameter change behind the treasury timelock
+///
+/// The change becomes executable with `execute_change` once `timelock_delay` seconds
+/// have passed and can be dropped with `cancel_change` until then. Fee and vault changes
+/// require the fee manager; changing the delay itself requires the admin.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `id` - caller-chosen identifier, used as the seed of the queued change PDA
+/// * `change` - the parameter change to schedule
+pub fn queue_change(
+    ctx: Context<QueueChangeInstructionAccounts>,
+    id: u64,
+    change: ParameterChange,
+) -> Result<()> {
+    let treasury: &Account<'_, Treasury> = &ctx.accounts.treasury_pda;
+
+    match &change {
+        ParameterChange::Treasury { fee_bps, deviation } => {
+            check_role(treasury, Role::FeeManager, &ctx.accounts.signer.key())?;
+            check_treasury_params(treasury, *fee_bps, *deviation)?;
+        }
+        ParameterChange::Vault { params, .. } => {
+            check_role(treasury, Role::FeeManager, &ctx.accounts.signer.key())?;
+            check_vault_params(treasury, params)?;
+
+            // The oracle must be a valid Pyth price account, checked now rather than at execution
+            let pyth_price_account = ctx.accounts.pyth_price_account.as_ref()
+                .ok_or(OxediumError::MissingPriceAccount)?;
+            require_keys_eq!(pyth_price_account.key(), params.pyth_price_account, OxediumError::InvalidPythAccount);
+        }
+        ParameterChange::TimelockDelay { delay } => {
+            check_admin(treasury, &ctx.accounts.signer)?;
+            require!(*delay >= 0, OxediumError::InvalidDuration);
+        }
+    }
+
+    let eta = Clock::get()?.unix_timestamp
+        .checked_add(treasury.timelock_delay)
+        .ok_or(OxediumError::OverflowInAdd)?;
+
+    let queued: &mut Account<'_, QueuedChange> = &mut ctx.accounts.queued_change;
+    queued.id = id;
+    queued.proposer = ctx.accounts.signer.key();
+    queued.eta = eta;
+    queued.change = change;
+
+    emit!(ChangeQueuedEvent {
+        id,
+        proposer: queued.proposer,
+        change,
+        eta
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `queue_change` instruction
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct QueueChangeInstructionAccounts<'info> {
+    /// The signer of the transaction (fee manager or admin); pays for the queued change
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Pyth price feed account, required for vault changes
+    pub pyth_price_account: Option<Account<'info, PriceUpdateV2>>,
+
+    /// The queued change PDA
+    ///
+    /// Seeds:
+    /// - `QUEUED_CHANGE_SEED`
+    /// - `id` (little-endian)
+    ///
+    /// `bump` is automatically derived
+    /// `space` (8 + 8 + 32 + 8 + 114 = 170 bytes):
+    /// - 8 bytes: Anchor account discriminator
+    /// - 8 bytes: `id` u64
+    /// - 32 bytes: `proposer` Pubkey — receives the rent back on execute/cancel
+    /// - 8 bytes: `eta` i64 — earliest execution time
+    /// - 114 bytes: `change` ParameterChange — 1 byte tag + largest variant (`Vault`: 32 + 81)
+    #[account(
+        init,
+        payer = signer,
+        seeds = [QUEUED_CHANGE_SEED.as_bytes(), id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + 8 + 32 + 8 + 114,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// The Treasury PDA account used for role checks and the timelock delay
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// System program (required to create the queued change)
+    pub system_program: Program<'info, System>,
+}
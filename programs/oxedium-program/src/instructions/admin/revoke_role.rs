@@ -0,0 +1,42 @@
+use crate::{components::{check_admin, set_role_holder}, events::RoleChanged, states::{Role, Treasury}, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Revoke an operational role; afterwards only the admin can perform it
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `role` - role to clear
+pub fn revoke_role(ctx: Context<RevokeRoleInstructionAccounts>, role: Role) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the treasury admin
+    check_admin(treasury, &ctx.accounts.signer)?;
+
+    let previous_holder = set_role_holder(treasury, role, Pubkey::default());
+
+    emit!(RoleChanged {
+        role,
+        previous_holder,
+        holder: Pubkey::default()
+    });
+
+    Ok(())
+}
+
+/// Accounts context for `revoke_role` instruction
+#[derive(Accounts)]
+pub struct RevokeRoleInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
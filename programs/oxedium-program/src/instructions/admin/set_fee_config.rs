@@ -0,0 +1,63 @@
+use crate::{components::{check_fee_recipients, check_role}, events::FeeConfigUpdated, states::{FeeConfig, FeeRecipient, Role, Treasury}, utils::{FEE_CONFIG_SEED, OXEDIUM_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+
+/// Set the wallets that receive collected protocol yield and their weights
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `recipients` - up to `MAX_FEE_RECIPIENTS` wallets; weights must sum to 10_000 bps
+pub fn set_fee_config(ctx: Context<SetFeeConfigInstructionAccounts>, recipients: Vec<FeeRecipient>) -> Result<()> {
+    // Ensure the caller is the fee collector (or admin)
+    check_role(&ctx.accounts.treasury_pda, Role::FeeCollector, &ctx.accounts.signer.key())?;
+
+    check_fee_recipients(&recipients)?;
+
+    let fee_config: &mut Account<'_, FeeConfig> = &mut ctx.accounts.fee_config;
+    fee_config.recipients = recipients;
+
+    emit!(FeeConfigUpdated {
+        signer: ctx.accounts.signer.key(),
+        recipients: fee_config.recipients.clone()
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `set_fee_config` instruction
+#[derive(Accounts)]
+pub struct SetFeeConfigInstructionAccounts<'info> {
+    /// The signer of the transaction (must be the fee collector or admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The FeeConfig PDA account, created on first use
+    ///
+    /// Seeds:
+    /// - `FEE_CONFIG_SEED`
+    ///
+    /// `bump` is automatically derived
+    /// `space` (8 + 4 + 5 * 40 = 212 bytes):
+    /// - 8 bytes: Anchor account discriminator
+    /// - 4 + 40 * `MAX_FEE_RECIPIENTS` bytes: `recipients` Vec<FeeRecipient> (32-byte owner + 8-byte weight)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [FEE_CONFIG_SEED.as_bytes()],
+        bump,
+        space = 8 + 4 + 5 * 40,
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    /// The Treasury PDA account used for role checks
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// System program (required to create the fee config)
+    pub system_program: Program<'info, System>,
+}
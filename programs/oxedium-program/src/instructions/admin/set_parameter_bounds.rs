@@ -0,0 +1,68 @@
+use crate::{components::{check_admin, check_parameter_bounds}, events::ParameterBoundsUpdated, states::Treasury, utils::{OXEDIUM_SEED, TREASURY_SEED}};
+use anchor_lang::prelude::*;
+
+/// Set the global bounds that vault and treasury parameters are validated against
+///
+/// Bounds only apply to future updates; parameters already in place are not re-checked.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `bounds` - new parameter bounds
+pub fn set_parameter_bounds(ctx: Context<SetParameterBoundsInstructionAccounts>, bounds: ParameterBounds) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+
+    // Ensure the signer is the treasury admin
+    check_admin(treasury, &ctx.accounts.signer)?;
+
+    check_parameter_bounds(&bounds)?;
+
+    let old = ParameterBounds {
+        max_base_fee: treasury.max_base_fee,
+        max_protocol_fee_bps: treasury.max_protocol_fee_bps,
+        max_oracle_age: treasury.max_oracle_age,
+        min_deviation: treasury.min_deviation,
+    };
+
+    treasury.max_base_fee = bounds.max_base_fee;
+    treasury.max_protocol_fee_bps = bounds.max_protocol_fee_bps;
+    treasury.max_oracle_age = bounds.max_oracle_age;
+    treasury.min_deviation = bounds.min_deviation;
+
+    emit!(ParameterBoundsUpdated {
+        old,
+        new: bounds
+    });
+
+    Ok(())
+}
+
+/// Global bounds for admin-set parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParameterBounds {
+    /// Maximum vault base fee in basis points
+    pub max_base_fee: u64,
+    /// Maximum protocol fee in basis points
+    pub max_protocol_fee_bps: u64,
+    /// Maximum accepted `max_age_price` of a vault, in seconds
+    pub max_oracle_age: u64,
+    /// Minimum treasury deviation divisor
+    pub min_deviation: u64,
+}
+
+/// Accounts context for `set_parameter_bounds` instruction
+#[derive(Accounts)]
+pub struct SetParameterBoundsInstructionAccounts<'info> {
+    /// The signer of the transaction (must be current admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,49 @@
+use crate::{components::check_role, events::Paused, states::{Role, Treasury}, utils::{TREASURY_SEED, OXEDIUM_SEED}};
+use anchor_lang::prelude::*;
+
+/// Pause or resume protocol operations
+///
+/// The pauser can only pause; lifting the stop-tap requires the guardian (or admin).
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `stoptap` - `true` to pause, `false` to resume
+pub fn set_stoptap(ctx: Context<SetStoptapInstructionAccounts>, stoptap: bool) -> Result<()> {
+    let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
+    let signer = ctx.accounts.signer.key();
+
+    // Either pauser or guardian may pause, only the guardian may resume
+    if stoptap {
+        check_role(treasury, Role::Pauser, &signer)
+            .or_else(|_| check_role(treasury, Role::Guardian, &signer))?;
+    } else {
+        check_role(treasury, Role::Guardian, &signer)?;
+    }
+
+    treasury.stoptap = stoptap;
+
+    emit!(Paused {
+        signer,
+        stoptap
+    });
+
+    Ok(())
+}
+
+/// Accounts context for `set_stoptap` instruction
+#[derive(Accounts)]
+pub struct SetStoptapInstructionAccounts<'info> {
+    /// The signer of the transaction (pauser, guardian or admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Treasury PDA account
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,65 @@
+use crate::{components::check_role, states::{Role, Treasury, Vault}, utils::{OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED}};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+/// Enable or disable allowlist mode on a vault and set its Merkle root
+///
+/// While enabled, only wallets with a valid Merkle proof against `root` can stake into
+/// or swap through the vault. Existing LPs can always exit via `unstaking`.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `enabled` - whether the vault is permissioned
+/// * `root` - Merkle root of the allowlisted wallets (see `allowlist_leaf`)
+pub fn set_vault_allowlist(
+    ctx: Context<SetVaultAllowlistInstructionAccounts>,
+    enabled: bool,
+    root: [u8; 32],
+) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+
+    // Ensure the caller is the vault lister (or admin)
+    check_role(&ctx.accounts.treasury_pda, Role::VaultLister, &ctx.accounts.signer.key())?;
+
+    vault.allowlist_enabled = enabled;
+    vault.allowlist_root = root;
+
+    msg!("SetVaultAllowlist {{mint: {}, enabled: {}, root: {:?}}}",
+        vault.token_mint.key(),
+        vault.allowlist_enabled,
+        vault.allowlist_root
+    );
+
+    Ok(())
+}
+
+/// Accounts context for the `set_vault_allowlist` instruction
+#[derive(Accounts)]
+pub struct SetVaultAllowlistInstructionAccounts<'info> {
+    /// The signer of the transaction (must be the vault lister or admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account
+    ///
+    /// Seeds:
+    /// - `VAULT_SEED`
+    /// - vault_mint key
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The Treasury PDA account used for role checks
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
@@ -0,0 +1,63 @@
+use crate::{components::check_role, events::VaultStatusChanged, states::{Role, Treasury, Vault, VaultStatus}, utils::{OxediumError, OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED}};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+/// Move a vault forward in its delisting lifecycle
+///
+/// `Active` → `SwapOnlyOut` blocks new stakes and inbound swaps while traders can still
+/// take the remaining tokens out; `WithdrawOnly` stops all swaps. Both waive the deficit
+/// unstake penalty. Once every LP has left, the vault can be removed with `close_vault`.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for this instruction
+/// * `status` - new status, must come after the current one
+pub fn set_vault_status(ctx: Context<SetVaultStatusInstructionAccounts>, status: VaultStatus) -> Result<()> {
+    // Ensure the caller is the vault lister (or admin)
+    check_role(&ctx.accounts.treasury_pda, Role::VaultLister, &ctx.accounts.signer.key())?;
+
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+
+    require!(status > vault.status, OxediumError::InvalidVaultStatus);
+
+    let old_status = vault.status;
+    vault.status = status;
+
+    emit!(VaultStatusChanged {
+        mint: vault.token_mint,
+        old_status,
+        status
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the `set_vault_status` instruction
+#[derive(Accounts)]
+pub struct SetVaultStatusInstructionAccounts<'info> {
+    /// The signer of the transaction (must be the vault lister or admin)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// The Vault PDA account
+    ///
+    /// Seeds:
+    /// - `VAULT_SEED`
+    /// - vault_mint key
+    ///
+    /// `bump` is automatically derived
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// The Treasury PDA account used for role checks
+    ///
+    /// Seeds:
+    /// - `OXEDIUM_SEED`
+    /// - `TREASURY_SEED`
+    ///
+    /// `bump` is automatically derived
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+}
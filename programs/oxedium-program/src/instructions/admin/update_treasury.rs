@@ -1,41 +1,35 @@
-use crate::{components::check_admin, states::Treasury, utils::{OxediumError, TREASURY_SEED, OXEDIUM_SEED}};
+use crate::{components::{apply_treasury_params, check_role, check_timelock_disabled, check_treasury_params}, states::{Role, Treasury}, utils::{TREASURY_SEED, OXEDIUM_SEED}};
 use anchor_lang::prelude::*;
 
-/// Update treasury settings: admin, stop-tap flag, protocol fee, and deviation
+/// Update treasury settings: protocol fee and deviation
+///
+/// Admin handover is not done here; use `propose_admin` / `accept_admin`.
+/// Pausing is done with `set_stoptap`. Only available while the timelock delay is zero;
+/// otherwise queue the change with `queue_change`.
 ///
 /// # Arguments
 /// * `ctx` - context containing all accounts required for this instruction
-/// * `stoptap` - boolean flag to pause or resume treasury operations
-/// * `protocol_fee_bps` - protocol fee in basis points (bps), taken from total swap fees
+/// * `protocol_fee_bps` - protocol fee in basis points (bps), taken from total swap fees;
+///   must be <= `max_protocol_fee_bps`
 /// * `deviation` - liquidity threshold divisor (e.g., 10 → 10% of current liquidity);
-///   must be > 0; swaps exceeding this fraction of the vault get a 10x fee penalty
+///   must be >= `min_deviation`; swaps exceeding this fraction of the vault get a 10x fee penalty
 #[inline(never)]
 pub fn update_treasury(
     ctx: Context<UpdateTreasuryInstructionAccounts>,
-    stoptap: bool,
     protocol_fee_bps: u64,
     deviation: u64
 ) -> Result<()> {
     let treasury: &mut Account<'_, Treasury> = &mut ctx.accounts.treasury_pda;
 
-    // Ensure the signer is the treasury admin
-    check_admin(treasury, &ctx.accounts.signer)?;
+    // Ensure the signer is the fee manager (or admin)
+    check_role(treasury, Role::FeeManager, &ctx.accounts.signer.key())?;
 
-    require!(deviation > 0, OxediumError::InvalidDeviation);
+    // Instant updates are only allowed while no timelock delay is set
+    check_timelock_disabled(treasury)?;
 
-    // Update the treasury fields
-    treasury.admin = ctx.accounts.new_admin.key(); // set new admin
-    treasury.stoptap = stoptap;                    // enable/disable operations
-    treasury.fee_bps = protocol_fee_bps;     // protocol fee in bps
-    treasury.deviation = deviation;
+    check_treasury_params(treasury, protocol_fee_bps, deviation)?;
 
-    // Log the update for transparency
-    msg!("UpdateTreasury {{admin: {}, stoptap: {}, protocol_fee: {}, deviation: {}}}", 
-        treasury.admin.key(), 
-        treasury.stoptap, 
-        treasury.fee_bps,
-        treasury.deviation
-    );
+    apply_treasury_params(treasury, protocol_fee_bps, deviation);
 
     Ok(())
 }
@@ -43,14 +37,10 @@ pub fn update_treasury(
 /// Accounts context for `update_treasury` instruction
 #[derive(Accounts)]
 pub struct UpdateTreasuryInstructionAccounts<'info> {
-    /// The signer of the transaction (must be current admin)
+    /// The signer of the transaction (must be the fee manager or admin)
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    /// The new admin public key to assign
-    /// CHECK: No constraints, assumed valid by admin
-    pub new_admin: AccountInfo<'info>,
-
     /// The Treasury PDA account
     ///
     /// Seeds:
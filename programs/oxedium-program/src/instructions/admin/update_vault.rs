@@ -1,43 +1,65 @@
-use crate::{components::check_admin, states::{Treasury, Vault}, utils::{OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED}};
+use crate::{components::{apply_vault_params, check_role, check_timelock_disabled, check_vault_params}, states::{Role, Treasury, Vault, VaultParams}, utils::{OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED}};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 /// Update vault parameters: activity status, base fee, and price feed settings
 ///
+/// Only available while the treasury timelock delay is zero; otherwise queue the
+/// change with `queue_change`.
+///
 /// # Arguments
 /// * `ctx` - context containing all accounts required for this instruction
 /// * `base_fee` - base fee for swaps involving this vault
 /// * `max_age_price` - maximum allowed age for the Pyth price feed (in seconds)
+/// * `min_stake_age` - seconds a deposit must stay staked before its yield vests
+/// * `yield_drip_window` - seconds over which swap fees are released to stakers
+/// * `deposit_caps` - vault and per-user deposit caps
 pub fn update_vault(
     ctx: Context<UpdateVaultInstructionAccounts>,
     base_fee: u64,
     max_age_price: u64,
+    min_stake_age: i64,
+    yield_drip_window: i64,
+    deposit_caps: DepositCaps,
 ) -> Result<()> {
-    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    // Ensure the caller is the fee manager (or admin)
+    check_role(&ctx.accounts.treasury_pda, Role::FeeManager, &ctx.accounts.signer.key())?;
+
+    // Instant updates are only allowed while no timelock delay is set
+    check_timelock_disabled(&ctx.accounts.treasury_pda)?;
 
-    // Ensure the caller is an admin using the Treasury account
-    check_admin(&ctx.accounts.treasury_pda, &ctx.accounts.signer)?;
+    let params = VaultParams {
+        base_fee,
+        pyth_price_account: ctx.accounts.pyth_price_account.key(),
+        max_age_price,
+        min_stake_age,
+        yield_drip_window,
+        deposit_caps,
+    };
 
-    // Update vault fields
-    vault.base_fee = base_fee;                       // set the base fee for the vault
-    vault.pyth_price_account = ctx.accounts.pyth_price_account.key(); // update Pyth price feed
-    vault.max_age_price = max_age_price;             // max allowed age of price feed
+    check_vault_params(&ctx.accounts.treasury_pda, &params)?;
 
-    // Log the update for transparency
-    msg!("UpdateVault {{mint: {}, base_fee: {}, max_age_price: {}}}", 
-        vault.token_mint.key(), 
-        vault.base_fee,
-        vault.max_age_price
-    );
+    apply_vault_params(&mut ctx.accounts.vault_pda, &params, Clock::get()?.unix_timestamp);
 
     Ok(())
 }
 
+/// Deposit caps of a vault; a cap of zero means no limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct DepositCaps {
+    /// Maximum total deposits (LP supply) of the vault
+    pub max_total_deposits: u64,
+    /// Maximum position of a single staker
+    pub max_deposit_per_user: u64,
+    /// Caps are USD values with `USD_DECIMALS` decimals instead of token units
+    pub caps_in_usd: bool,
+}
+
 /// Accounts context for the `update_vault` instruction
 #[derive(Accounts)]
 pub struct UpdateVaultInstructionAccounts<'info> {
-    /// The signer of the transaction (must be the admin)
+    /// The signer of the transaction (must be the fee manager or admin)
     #[account(mut)]
     pub signer: Signer<'info>,
 
@@ -57,7 +79,7 @@ pub struct UpdateVaultInstructionAccounts<'info> {
     #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
     pub vault_pda: Account<'info, Vault>,
 
-    /// The Treasury PDA account used for admin checks
+    /// The Treasury PDA account used for role checks
     ///
     /// Seeds:
     /// - `OXEDIUM_SEED`
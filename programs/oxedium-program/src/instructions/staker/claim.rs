@@ -1,40 +1,50 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::{components::{calculate_staker_yield, check_stoptap}, events::ClaimEvent, states::{Staker, Treasury, Vault}, utils::{MINT_SEED, OXEDIUM_SEED, STAKER_SEED, TREASURY_SEED, VAULT_SEED}};
+use crate::{components::{check_staker_authority, check_stoptap, settle_staker_rewards, settle_staker_yield}, events::ClaimEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::{OxediumError, MINT_SEED, OXEDIUM_SEED, REWARD_STREAM_SEED, STAKER_SEED, TREASURY_SEED, VAULT_SEED}};
 
 /// Claim accumulated yield for a staker from a vault
 ///
+/// The owner may send the yield to any token account of the vault token; a delegate
+/// can only claim into token accounts owned by the position owner. Whatever is not
+/// claimed stays in `pending_claim`.
+///
 /// # Arguments
 /// * `ctx` - context containing all accounts required for claiming
-pub fn claim(ctx: Context<ClaimInstructionAccounts>) -> Result<()> {
+/// * `amount` - amount of yield to claim, `None` to claim everything
+pub fn claim(ctx: Context<ClaimInstructionAccounts>, amount: Option<u64>) -> Result<()> {
     let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
     let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
 
     // Check if vault is active and stop-tap is not enabled
     check_stoptap(&ctx.accounts.treasury_pda)?;
+    check_staker_authority(staker, &ctx.accounts.signer.key())?;
+
+    // Delegates cannot redirect the owner's yield
+    if ctx.accounts.signer.key() != staker.owner && ctx.accounts.recipient_ata.owner != staker.owner {
+        return Err(OxediumError::InvalidRecipient.into());
+    }
 
-    // Get cumulative yield per LP token from the vault
-    let cumulative_yield_per_lp: u128 = vault.cumulative_yield_per_lp;
     // Get the staker's LP token balance
-    let staker_lp: u64 = ctx.accounts.signer_lp_ata.amount;
-    // Get the last cumulative yield recorded for the staker
-    let staker_last_cumulative_yield: u128 = staker.last_cumulative_yield;
-    // Get the pending claim for the staker
-    let staker_pending_claim: u64 = staker.pending_claim;
+    let staker_lp: u64 = ctx.accounts.owner_lp_ata.amount;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Calculate total yield: new yield + pending claim (unvested yield stays behind)
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    let amount: u64 = amount.unwrap_or(staker.pending_claim);
 
-    // Calculate total yield: new yield + pending claim
-    let staker_yield: u64 = calculate_staker_yield(cumulative_yield_per_lp, staker_lp, staker_last_cumulative_yield);
-    let amount: u64 = staker_yield + staker_pending_claim;
+    if amount > staker.pending_claim {
+        return Err(OxediumError::InsufficientClaim.into());
+    }
 
     // PDA seeds for signing transfer from treasury
     let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
     let signer_seeds = &[&seeds[..]];
 
-    // Define CPI transfer from treasury to staker
+    // Define CPI transfer from treasury to the recipient
     let cpi_accounts = Transfer {
         from: ctx.accounts.treasury_ata.to_account_info(),
-        to: ctx.accounts.signer_ata.to_account_info(), 
+        to: ctx.accounts.recipient_ata.to_account_info(), 
         authority: ctx.accounts.treasury_pda.to_account_info()
     };
 
@@ -47,13 +57,17 @@ pub fn claim(ctx: Context<ClaimInstructionAccounts>) -> Result<()> {
         amount)?;
 
     // Update staker PDA state
-    staker.last_cumulative_yield = cumulative_yield_per_lp;
-    staker.pending_claim = 0;
+    staker.pending_claim -= amount;
+    vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(amount);
+
+    // Keep liquidity mining rewards in sync with the yield checkpoint
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
     
     emit!(ClaimEvent {
-        user: ctx.accounts.signer.key(),
+        user: ctx.accounts.owner.key(),
         mint: vault.token_mint.key(),
-        amount: amount
+        recipient: ctx.accounts.recipient_ata.key(),
+        amount
     });
 
     Ok(())
@@ -63,7 +77,11 @@ pub fn claim(ctx: Context<ClaimInstructionAccounts>) -> Result<()> {
 #[derive(Accounts)]
 pub struct ClaimInstructionAccounts<'info> {
     #[account(mut)]
-    pub signer: Signer<'info>, // staker claiming rewards
+    pub signer: Signer<'info>, // staker owner or its delegate
+
+    /// Owner of the staker position
+    /// CHECK: only used for PDA derivation and token account authority checks
+    pub owner: UncheckedAccount<'info>,
 
     /// Vault token mint
     /// CHECK: no constraints
@@ -79,22 +97,26 @@ pub struct ClaimInstructionAccounts<'info> {
     )]
     pub lp_mint: Account<'info, Mint>,
 
-    /// Staker's LP token account
+    /// Owner's LP token account
     #[account(
         mut,
         associated_token::mint = lp_mint,
-        associated_token::authority = signer,
+        associated_token::authority = owner,
     )]
-    pub signer_lp_ata: Account<'info, TokenAccount>,
+    pub owner_lp_ata: Account<'info, TokenAccount>,
 
-    /// Staker's vault token account to receive claimed yield
-    #[account(mut, token::authority = signer, token::mint = vault_mint)]
-    pub signer_ata: Account<'info, TokenAccount>,
+    /// Vault token account to receive claimed yield
+    #[account(mut, token::mint = vault_mint)]
+    pub recipient_ata: Account<'info, TokenAccount>,
 
     /// Staker PDA storing last yield and pending claim
-    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), owner.key().as_ref()], bump)]
     pub staker_pda: Account<'info, Staker>,
 
+    /// Vault reward stream, required if the vault has one
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>,
+
     /// Vault PDA storing cumulative yield and liquidity
     #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
     pub vault_pda: Account<'info, Vault>,
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{components::{apply_swap_result, check_allowlist, check_oracle, check_staker_authority, check_stoptap, check_vault_outflow, compute_swap_math, settle_staker_rewards, settle_staker_yield}, events::ClaimAsEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Claim accumulated yield converted into another vault's token
+///
+/// The claimed yield is swapped through `compute_swap_math` with the normal swap and
+/// protocol fees. The yield tokens never leave the treasury: like a swap's input they join
+/// the staked vault's current liquidity, while the output vault pays out and earns the fees.
+/// The same owner/delegate rules as `claim` apply to the recipient.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of yield to claim, `None` to claim everything
+/// * `minimum_out` - minimum amount of output tokens
+/// * `proof_out` - Merkle proof of the owner for an allowlisted output vault
+#[inline(never)]
+pub fn claim_as(ctx: Context<ClaimAsInstructionAccounts>, amount: Option<u64>, minimum_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+    let treasury: Account<'_, Treasury> = ctx.accounts.treasury_pda.clone();
+    let vault_in: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_in;
+    let vault_out: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_out;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&treasury)?;
+    check_staker_authority(staker, &ctx.accounts.signer.key())?;
+    check_allowlist(vault_out, &staker.owner, &proof_out)?;
+    check_vault_outflow(vault_out)?;
+
+    // Delegates cannot redirect the owner's yield
+    if ctx.accounts.signer.key() != staker.owner && ctx.accounts.recipient_ata.owner != staker.owner {
+        return Err(OxediumError::InvalidRecipient.into());
+    }
+
+    // Validate Pyth price accounts and check price feed freshness
+    let oracle_in: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_in.clone();
+    let oracle_out: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_out.clone();
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    check_oracle(vault_in, &oracle_in, current_timestamp)?;
+    check_oracle(vault_out, &oracle_out, current_timestamp)?;
+
+    // Settle the staker's yield in the staked vault
+    let staker_lp: u64 = ctx.accounts.owner_lp_ata.amount;
+    settle_staker_yield(vault_in, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault_in, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    let amount: u64 = amount.unwrap_or(staker.pending_claim);
+
+    if amount > staker.pending_claim {
+        return Err(OxediumError::InsufficientClaim.into());
+    }
+
+    // Convert the yield through the regular swap path
+    let result = compute_swap_math(
+        amount,
+        oracle_in.price_message,
+        oracle_out.price_message,
+        ctx.accounts.mint_in.decimals,
+        ctx.accounts.mint_out.decimals,
+        vault_in,
+        vault_out,
+        &treasury,
+    )?;
+
+    if result.net_amount_out < minimum_out {
+        return Err(OxediumError::HighSlippage.into());
+    }
+
+    staker.pending_claim -= amount;
+    vault_in.unclaimed_yield = vault_in.unclaimed_yield.saturating_sub(amount);
+
+    apply_swap_result(vault_in, vault_out, amount, &result, current_timestamp);
+
+    // Transfer output tokens from treasury to the recipient
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_ata_out.to_account_info(),
+        to: ctx.accounts.recipient_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        result.net_amount_out,
+    )?;
+
+    emit!(ClaimAsEvent {
+        user: staker.owner,
+        recipient: ctx.accounts.recipient_ata.key(),
+        fee_bps: result.swap_fee_bps + treasury.fee_bps,
+        token_in: vault_in.token_mint,
+        token_out: vault_out.token_mint,
+        amount_in: amount,
+        amount_out: result.net_amount_out,
+        lp_fee: result.lp_fee_amount,
+        protocol_fee: result.protocol_fee_amount
+    });
+
+    Ok(())
+}
+
+/// Accounts required for the claim_as instruction
+#[derive(Accounts)]
+pub struct ClaimAsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker owner or its delegate
+
+    /// CHECK: owner of the staker position, only used for PDA derivation and token account authority checks
+    pub owner: UncheckedAccount<'info>,
+
+    pub mint_in: Account<'info, Mint>,  // token mint of the staked vault
+    pub mint_out: Account<'info, Mint>, // token mint the yield is paid in
+
+    pub pyth_price_account_in: Account<'info, PriceUpdateV2>, // Pyth price feed for the staked token
+    pub pyth_price_account_out: Account<'info, PriceUpdateV2>, // Pyth price feed for the output token
+
+    #[account(
+        seeds = [MINT_SEED.as_bytes(), vault_pda_in.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>, // LP token mint of the staked vault
+
+    #[account(associated_token::mint = lp_mint, associated_token::authority = owner)]
+    pub owner_lp_ata: Account<'info, TokenAccount>, // owner's LP token account
+
+    #[account(mut, token::mint = mint_out)]
+    pub recipient_ata: Account<'info, TokenAccount>, // token account receiving the converted yield
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_in.key().as_ref()], bump)]
+    pub vault_pda_in: Account<'info, Vault>, // staked vault
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_out.key().as_ref()], bump)]
+    pub vault_pda_out: Account<'info, Vault>, // output vault
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda_in.key().as_ref(), owner.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>, // staker PDA in the staked vault
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda_in.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // staked vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA
+
+    #[account(mut, token::authority = treasury_pda, token::mint = mint_out)]
+    pub treasury_ata_out: Account<'info, TokenAccount>, // treasury output token account
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{components::{check_stoptap, claim_group_addresses, claim_group_count, settle_staker_yield, CLAIM_MANY_GROUP_SIZE}, events::ClaimEvent, states::{Staker, Treasury, Vault}, utils::{OxediumError, OXEDIUM_SEED, TREASURY_SEED}};
+
+/// Claim accumulated yield from several vaults in one instruction
+///
+/// `remaining_accounts` holds one group per vault, in this order:
+/// 1. vault PDA (writable)
+/// 2. signer's staker PDA of that vault (writable)
+/// 3. signer's LP associated token account of that vault
+/// 4. treasury token account of the vault token (writable)
+/// 5. signer's token account of the vault token receiving the yield (writable)
+///
+/// Every PDA derivation and token account is validated. Liquidity mining rewards are
+/// not touched since claiming does not change LP balances.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for claiming
+pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimManyInstructionAccounts<'info>>) -> Result<()> {
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    let remaining = ctx.remaining_accounts;
+
+    claim_group_count(remaining.len())?;
+
+    let signer: Pubkey = ctx.accounts.signer.key();
+    let treasury: Pubkey = ctx.accounts.treasury_pda.key();
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    for group in remaining.chunks(CLAIM_MANY_GROUP_SIZE) {
+        let mut vault: Account<'info, Vault> = Account::try_from(&group[0])?;
+        let mut staker: Account<'info, Staker> = Account::try_from(&group[1])?;
+        let lp_ata: Account<'info, TokenAccount> = Account::try_from(&group[2])?;
+        let treasury_ata: Account<'info, TokenAccount> = Account::try_from(&group[3])?;
+        let signer_ata: Account<'info, TokenAccount> = Account::try_from(&group[4])?;
+
+        // Validate PDA derivations
+        let (vault_key, staker_key, lp_ata_key) = claim_group_addresses(&signer, &vault.token_mint, &vault.lp_mint);
+
+        if vault.key() != vault_key || staker.key() != staker_key || lp_ata.key() != lp_ata_key {
+            return Err(OxediumError::InvalidRemainingAccounts.into());
+        }
+
+        // Validate token accounts
+        if lp_ata.mint != vault.lp_mint || lp_ata.owner != signer
+            || treasury_ata.mint != vault.token_mint || treasury_ata.owner != treasury
+            || signer_ata.mint != vault.token_mint || signer_ata.owner != signer
+        {
+            return Err(OxediumError::InvalidRemainingAccounts.into());
+        }
+
+        settle_staker_yield(&mut vault, &mut staker, lp_ata.amount, current_timestamp);
+        let amount: u64 = staker.pending_claim;
+
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from: treasury_ata.to_account_info(),
+                to: signer_ata.to_account_info(),
+                authority: ctx.accounts.treasury_pda.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+                amount,
+            )?;
+
+            staker.pending_claim = 0;
+            vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(amount);
+        }
+
+        // Persist before the next group so a repeated vault sees the updated state
+        vault.exit(ctx.program_id)?;
+        staker.exit(ctx.program_id)?;
+
+        emit!(ClaimEvent {
+            user: signer,
+            mint: vault.token_mint,
+            recipient: signer_ata.key(),
+            amount
+        });
+    }
+
+    Ok(())
+}
+
+/// Accounts context for the claim_many instruction, vault groups go in `remaining_accounts`
+#[derive(Accounts)]
+pub struct ClaimManyInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker claiming yield
+
+    /// Treasury PDA used to sign yield transfers
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{components::{check_stoptap, settle_staker_rewards, settle_staker_yield}, events::ClaimEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Claim accumulated yield of an NFT position
+///
+/// Callable by the current holder of the position NFT. Whatever is not claimed stays in
+/// the position's `pending_claim` and moves with the NFT.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of yield to claim, `None` to claim everything
+pub fn claim_position(ctx: Context<ClaimPositionInstructionAccounts>, amount: Option<u64>) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    let staker_lp: u64 = ctx.accounts.position_escrow.amount;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    let amount: u64 = amount.unwrap_or(staker.pending_claim);
+
+    if amount > staker.pending_claim {
+        return Err(OxediumError::InsufficientClaim.into());
+    }
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_ata.to_account_info(),
+        to: ctx.accounts.recipient_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount,
+    )?;
+
+    staker.pending_claim -= amount;
+    vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(amount);
+
+    emit!(ClaimEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint.key(),
+        recipient: ctx.accounts.recipient_ata.key(),
+        amount
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the claim_position instruction
+#[derive(Accounts)]
+pub struct ClaimPositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // holder of the position NFT
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>, // LP token mint controlled by treasury
+
+    pub position_mint: Box<Account<'info, Mint>>, // position NFT mint
+
+    #[account(
+        token::mint = position_mint,
+        token::authority = signer,
+        constraint = holder_position_ata.amount == 1 @ OxediumError::NotPositionHolder
+    )]
+    pub holder_position_ata: Box<Account<'info, TokenAccount>>, // holder's position NFT account
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the position's LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity and yield info
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()], bump)]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA
+
+    #[account(
+        mut,
+        associated_token::mint = vault_mint,
+        associated_token::authority = treasury_pda,
+    )]
+    pub treasury_ata: Box<Account<'info, TokenAccount>>, // treasury token account holding vault tokens
+
+    #[account(mut, token::mint = vault_mint)]
+    pub recipient_ata: Box<Account<'info, TokenAccount>>, // token account receiving the vault tokens
+
+    pub token_program: Program<'info, Token>,
+}
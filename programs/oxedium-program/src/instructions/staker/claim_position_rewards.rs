@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount, Transfer}};
+
+use crate::{components::{check_stoptap, settle_staker_rewards}, events::RewardClaimEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Claim accumulated liquidity mining rewards of an NFT position
+///
+/// Callable by the current holder of the position NFT; the rewards are paid to the
+/// holder's reward token account.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for claiming
+pub fn claim_position_rewards(ctx: Context<ClaimPositionRewardsInstructionAccounts>) -> Result<()> {
+    let vault: &Account<'_, Vault> = &ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    // Accrue the stream and settle the position's rewards
+    let staker_lp: u64 = ctx.accounts.position_escrow.amount;
+    settle_staker_rewards(vault, Some(stream), staker, staker_lp, Clock::get()?.unix_timestamp)?;
+
+    let amount: u64 = staker.pending_rewards;
+
+    // PDA seeds for signing transfer from escrow
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reward_escrow.to_account_info(),
+        to: ctx.accounts.signer_reward_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info()
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount,
+    )?;
+
+    staker.pending_rewards = 0;
+
+    emit!(RewardClaimEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the claim_position_rewards instruction
+#[derive(Accounts)]
+pub struct ClaimPositionRewardsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // holder of the position NFT
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(address = reward_stream.reward_mint)]
+    pub reward_mint: Box<Account<'info, Mint>>, // reward token mint of the stream
+
+    pub position_mint: Box<Account<'info, Mint>>, // position NFT mint
+
+    #[account(
+        token::mint = position_mint,
+        token::authority = signer,
+        constraint = holder_position_ata.amount == 1 @ OxediumError::NotPositionHolder
+    )]
+    pub holder_position_ata: Box<Account<'info, TokenAccount>>, // holder's position NFT account
+
+    #[account(seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the position's LP tokens
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_reward_ata: Box<Account<'info, TokenAccount>>, // holder's reward token account
+
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()], bump)]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Account<'info, RewardStream>, // vault reward stream
+
+    #[account(mut, seeds = [REWARD_ESCROW_SEED.as_bytes(), reward_stream.key().as_ref()], bump)]
+    pub reward_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the stream funding
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA signing the reward transfer
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount, Transfer}};
+
+use crate::{components::{check_stoptap, settle_staker_rewards}, events::RewardClaimEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Claim accumulated liquidity mining rewards for a staker from a vault's reward stream
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for claiming
+pub fn claim_rewards(ctx: Context<ClaimRewardsInstructionAccounts>) -> Result<()> {
+    let vault: &Account<'_, Vault> = &ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+    let stream: &mut Account<'_, RewardStream> = &mut ctx.accounts.reward_stream;
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    // Accrue the stream and settle the staker's rewards
+    let staker_lp: u64 = ctx.accounts.signer_lp_ata.amount;
+    settle_staker_rewards(vault, Some(stream), staker, staker_lp, Clock::get()?.unix_timestamp)?;
+
+    let amount: u64 = staker.pending_rewards;
+
+    // PDA seeds for signing transfer from escrow
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Define CPI transfer from escrow to staker
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reward_escrow.to_account_info(),
+        to: ctx.accounts.signer_reward_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info()
+    };
+
+    // Execute the transfer using PDA signer
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds),
+        amount)?;
+
+    // Update staker PDA state
+    staker.pending_rewards = 0;
+
+    emit!(RewardClaimEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the claim_rewards instruction
+#[derive(Accounts)]
+pub struct ClaimRewardsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker claiming rewards
+
+    /// Vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// Reward token mint of the stream
+    #[account(address = reward_stream.reward_mint)]
+    pub reward_mint: Account<'info, Mint>,
+
+    /// LP token mint controlled by treasury
+    #[account(
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// Staker's LP token account
+    #[account(
+        associated_token::mint = lp_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_lp_ata: Account<'info, TokenAccount>,
+
+    /// Staker's reward token account to receive claimed rewards
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    /// Staker PDA storing last reward checkpoint and pending rewards
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>,
+
+    /// Vault PDA storing liquidity
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// Vault reward stream
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Account<'info, RewardStream>,
+
+    /// Escrow token account holding the stream funding
+    #[account(mut, seeds = [REWARD_ESCROW_SEED.as_bytes(), reward_stream.key().as_ref()], bump)]
+    pub reward_escrow: Account<'info, TokenAccount>,
+
+    /// Treasury PDA used to sign reward transfer
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
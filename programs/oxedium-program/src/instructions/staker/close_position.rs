@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, burn, Burn, CloseAccount, Mint, Token, TokenAccount};
+use crate::{components::{check_stoptap, forfeit_unvested_yield, settle_staker_rewards, settle_staker_yield}, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Close an emptied NFT position and refund its rent to the holder
+///
+/// The position escrow must hold no LP and the position no claimable yield or rewards
+/// (claim them first with `claim_position` and `claim_position_rewards`). Yield that has not vested yet is forfeited. The position NFT is
+/// burned, then the escrow, the holder's NFT account and the position record are closed
+/// with rent going to the holder.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+pub fn close_position(ctx: Context<ClosePositionInstructionAccounts>) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    if ctx.accounts.position_escrow.amount > 0 {
+        return Err(OxediumError::StakerNotEmpty.into());
+    }
+
+    // Settle the last yield; anything still unvested goes back to the vault
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+    settle_staker_yield(vault, staker, 0, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, 0, current_timestamp)?;
+    forfeit_unvested_yield(vault, staker, 0, 0, current_timestamp);
+
+    if staker.pending_claim > 0 || staker.pending_rewards > 0 {
+        return Err(OxediumError::StakerNotEmpty.into());
+    }
+
+    vault.open_stakers = vault.open_stakers.saturating_sub(1);
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Close the empty LP escrow
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.position_escrow.to_account_info(),
+        destination: ctx.accounts.signer.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds))?;
+
+    // Burn the position NFT and close the holder's NFT account
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.position_mint.to_account_info(),
+        from: ctx.accounts.holder_position_ata.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), 1)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.holder_position_ata.to_account_info(),
+        destination: ctx.accounts.signer.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    token::close_account(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts))?;
+
+    msg!("ClosePosition {{position_mint: {}, mint: {}}}", ctx.accounts.position_mint.key(), vault.token_mint);
+
+    Ok(())
+}
+
+/// Accounts context for the close_position instruction
+#[derive(Accounts)]
+pub struct ClosePositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // holder of the position NFT, receives the rent
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(mut)]
+    pub position_mint: Box<Account<'info, Mint>>, // position NFT mint
+
+    #[account(
+        mut,
+        token::mint = position_mint,
+        token::authority = signer,
+        constraint = holder_position_ata.amount == 1 @ OxediumError::NotPositionHolder
+    )]
+    pub holder_position_ata: Box<Account<'info, TokenAccount>>, // holder's position NFT account, closed
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // empty escrow of the position's LP tokens, closed
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity and yield info
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()],
+        bump
+    )]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record, closed
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA, authority of the escrow
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{components::{check_stoptap, forfeit_unvested_yield, settle_staker_yield}, events::ClaimEvent, states::{Staker, Treasury, Vault}, utils::{OxediumError, MINT_SEED, OXEDIUM_SEED, STAKER_SEED, TREASURY_SEED, VAULT_SEED}};
+
+/// Close an empty Staker PDA and refund its rent to the owner
+///
+/// The position must hold no LP (liquid or locked) and no unclaimed liquidity mining
+/// rewards. Any remaining `pending_claim` yield is claimed to the owner before closing.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts required for closing
+/// * `close_lp_ata` - also close the owner's empty LP token account
+pub fn close_staker(ctx: Context<CloseStakerInstructionAccounts>, close_lp_ata: bool) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    if ctx.accounts.signer_lp_ata.amount > 0 || staker.locked_amount > 0 || staker.pending_rewards > 0 {
+        return Err(OxediumError::StakerNotEmpty.into());
+    }
+
+    // Settle the last yield; anything still unvested goes back to the vault
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+    settle_staker_yield(vault, staker, 0, current_timestamp);
+    forfeit_unvested_yield(vault, staker, 0, 0, current_timestamp);
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Auto-claim the remaining yield
+    let amount: u64 = staker.pending_claim;
+
+    if amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_ata.to_account_info(),
+            to: ctx.accounts.signer_ata.to_account_info(),
+            authority: ctx.accounts.treasury_pda.to_account_info()
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+            amount,
+        )?;
+
+        staker.pending_claim = 0;
+        vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(amount);
+
+        emit!(ClaimEvent {
+            user: ctx.accounts.signer.key(),
+            mint: vault.token_mint.key(),
+            recipient: ctx.accounts.signer_ata.key(),
+            amount
+        });
+    }
+
+    vault.open_stakers = vault.open_stakers.saturating_sub(1);
+
+    // Close the empty LP token account and refund its rent as well
+    if close_lp_ata {
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.signer_lp_ata.to_account_info(),
+            destination: ctx.accounts.signer.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        };
+        token::close_account(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts))?;
+    }
+
+    msg!("CloseStaker {{owner: {}, mint: {}, close_lp_ata: {}}}", ctx.accounts.signer.key(), vault.token_mint, close_lp_ata);
+
+    Ok(())
+}
+
+/// Accounts context for the close_staker instruction
+#[derive(Accounts)]
+pub struct CloseStakerInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker closing the position
+
+    /// Vault token mint
+    pub vault_mint: Account<'info, Mint>,
+
+    /// LP token mint controlled by treasury
+    #[account(seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// Staker's LP token account, must be empty
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_lp_ata: Account<'info, TokenAccount>,
+
+    /// Staker's vault token account to receive the remaining yield
+    #[account(mut, token::authority = signer, token::mint = vault_mint)]
+    pub signer_ata: Account<'info, TokenAccount>,
+
+    /// Staker PDA to close, rent is refunded to the signer
+    #[account(
+        mut,
+        close = signer,
+        seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub staker_pda: Account<'info, Staker>,
+
+    /// Vault PDA storing cumulative yield and liquidity
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    /// Treasury PDA used to sign yield transfer
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    /// Treasury token account holding protocol/staker funds
+    #[account(
+        mut,
+        associated_token::mint = vault_mint,
+        associated_token::authority = treasury_pda,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
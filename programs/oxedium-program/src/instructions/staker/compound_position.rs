@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{check_deposit_caps, check_oracle, check_stoptap, check_vault_inflow, settle_staker_rewards, settle_staker_yield}, events::StakingEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Restake the accumulated yield of an NFT position
+///
+/// Callable by the current holder of the position NFT. The yield tokens already sit in
+/// the treasury, so no tokens move: they join the vault's current liquidity and new LP
+/// tokens are minted into the position escrow. The vesting clock is not reset. The new LP counts
+/// against the vault's deposit caps like any other deposit.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+pub fn compound_position(ctx: Context<CompoundPositionInstructionAccounts>) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+    check_vault_inflow(vault)?;
+
+    let staker_lp: u64 = ctx.accounts.position_escrow.amount;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    let amount: u64 = staker.pending_claim;
+
+    // Enforce the vault's deposit caps, valuing the deposit via the oracle if caps are in USD
+    let price_message = match &ctx.accounts.pyth_price_account {
+        Some(oracle) => {
+            check_oracle(vault, oracle, current_timestamp)?;
+            Some(oracle.price_message)
+        }
+        None => None,
+    };
+    check_deposit_caps(vault, staker_lp, amount, ctx.accounts.vault_mint.decimals, price_message.as_ref())?;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts: MintTo<'_> = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.position_escrow.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount,
+    )?;
+
+    staker.pending_claim = 0;
+    vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(amount);
+    vault.initial_liquidity += amount;
+    vault.current_liquidity += amount;
+
+    emit!(StakingEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint.key(),
+        amount
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the compound_position instruction
+#[derive(Accounts)]
+pub struct CompoundPositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // holder of the position NFT
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>, // LP token mint controlled by treasury
+
+    pub position_mint: Box<Account<'info, Mint>>, // position NFT mint
+
+    #[account(
+        token::mint = position_mint,
+        token::authority = signer,
+        constraint = holder_position_ata.amount == 1 @ OxediumError::NotPositionHolder
+    )]
+    pub holder_position_ata: Box<Account<'info, TokenAccount>>, // holder's position NFT account
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the position's LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity and yield info
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()], bump)]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    pub pyth_price_account: Option<Box<Account<'info, PriceUpdateV2>>>, // Pyth price feed, required for USD deposit caps
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, burn, Burn, Mint, Token, TokenAccount, Transfer}};
+use crate::{components::{accrue_vault_yield, emergency_withdraw_amount, settle_staker_rewards, settle_staker_yield}, events::EmergencyWithdrawEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Burn LP tokens for a pro-rata share of the vault's remaining balance while the protocol is paused
+///
+/// Only available while `Treasury::stoptap` is active, when `unstaking` and `claim` are blocked.
+/// Skips all oracle-dependent and dynamic-fee logic. The position is settled first; the
+/// withdrawn share of the yield earned since the staker's last settlement is forfeited to
+/// the remaining LPs, the rest of the yield and all liquidity mining rewards stay
+/// claimable once the protocol resumes. Tokens owed as unclaimed yield are excluded from
+/// the pro-rata payout, so they are not paid twice.
+/// Locked LP stays in escrow and is not affected.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of LP tokens to burn
+#[inline(never)]
+pub fn emergency_withdraw(ctx: Context<EmergencyWithdrawInstructionAccounts>, amount: u64) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    if !ctx.accounts.treasury_pda.stoptap {
+        return Err(OxediumError::NotPaused.into());
+    }
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+    let staker_lp: u64 = ctx.accounts.signer_lp_ata.amount;
+
+    // Settle the whole position before its LP balance changes
+    let settled: u64 = staker.pending_claim + staker.unvested_claim;
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    // Only the withdrawn share of the newly settled yield is forfeited, unvested first
+    let unsettled: u64 = staker.pending_claim + staker.unvested_claim - settled;
+    let position: u64 = staker_lp + staker.locked_amount;
+    let forfeited: u64 = (unsettled as u128 * amount as u128 / position.max(1) as u128) as u64;
+    let from_unvested: u64 = forfeited.min(staker.unvested_claim);
+    staker.unvested_claim -= from_unvested;
+    staker.pending_claim -= forfeited - from_unvested;
+
+    let amount_out: u64 = emergency_withdraw_amount(vault, ctx.accounts.treasury_ata.amount, amount)?;
+
+    // Burn LP tokens
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.signer_lp_ata.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    // Transfer the pro-rata share from treasury to staker
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_ata.to_account_info(),
+        to: ctx.accounts.signer_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount_out,
+    )?;
+
+    // Update vault liquidity
+    vault.initial_liquidity -= amount;
+    vault.current_liquidity -= amount_out;
+
+    // Pay the forfeited yield out to the remaining LPs
+    if forfeited > 0 {
+        vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(forfeited);
+        accrue_vault_yield(vault, forfeited, current_timestamp);
+    }
+
+    emit!(EmergencyWithdrawEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint.key(),
+        lp_amount: amount,
+        amount: amount_out
+    });
+
+    Ok(())
+}
+
+/// Accounts required for the emergency_withdraw instruction
+#[derive(Accounts)]
+pub struct EmergencyWithdrawInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut, token::authority = signer, token::mint = token_mint)]
+    pub signer_ata: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = signer, token::mint = lp_mint)]
+    pub signer_lp_ata: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), &token_mint.to_account_info().key.to_bytes()], bump)]
+    pub vault_pda: Account<'info, Vault>,
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>,
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>,
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>,
+
+    #[account(mut, token::authority = treasury_pda, token::mint = token_mint)]
+    pub treasury_ata: Account<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
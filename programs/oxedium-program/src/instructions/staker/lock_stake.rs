@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{components::{boost_weight, check_stoptap, lock_boost_bps, settle_staker_rewards, settle_staker_yield}, events::LockEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Lock LP tokens for a fixed period in exchange for a boosted share of swap fees
+///
+/// The LP tokens are moved into a per-staker escrow until `unlock_stake`.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts for locking
+/// * `amount` - amount of LP tokens to lock
+/// * `lock_days` - lock duration in days (30, 90 or 180)
+#[inline(never)]
+pub fn lock_stake(ctx: Context<LockStakeInstructionAccounts>, amount: u64, lock_days: u16) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    // Only one active lock per staker
+    require!(staker.locked_amount == 0, OxediumError::LockActive);
+
+    let boost_bps: u64 = lock_boost_bps(lock_days)?;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Settle with the unboosted weight before the lock starts
+    let staker_lp: u64 = ctx.accounts.signer_lp_ata.amount;
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    // Move LP tokens into the lock escrow
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.signer_lp_ata.to_account_info(),
+        to: ctx.accounts.lock_escrow.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    // Record the lock on the staker and its boosted weight on the vault
+    staker.locked_amount = amount;
+    staker.lock_end = current_timestamp + lock_days as i64 * 86_400;
+    staker.boost_bps = boost_bps;
+
+    vault.total_locked += amount;
+    vault.total_boost_weight += boost_weight(amount, boost_bps);
+
+    emit!(LockEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        amount,
+        lock_end: staker.lock_end,
+        boost_bps
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the lock_stake instruction
+#[derive(Accounts)]
+pub struct LockStakeInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // the staker locking LP tokens
+
+    pub vault_mint: Account<'info, Mint>, // vault token mint
+
+    #[account(
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>, // LP token mint controlled by treasury
+
+    #[account(mut, token::authority = signer, token::mint = lp_mint)]
+    pub signer_lp_ata: Account<'info, TokenAccount>, // staker's LP token account
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [LOCK_SEED.as_bytes(), staker_pda.key().as_ref()],
+        bump,
+        token::mint = lp_mint,
+        token::authority = treasury_pda,
+    )]
+    pub lock_escrow: Account<'info, TokenAccount>, // escrow holding the locked LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>, // vault PDA storing liquidity and yield info
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>, // staker PDA storing the lock
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA, authority of the lock escrow
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, burn, Burn, Mint, MintTo, Token, TokenAccount}};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{accrue_vault_yield, calculate_fee_amount, check_allowlist, check_deposit_caps, check_distinct_vaults, check_oracle, check_stoptap, check_vault_inflow, conf_fee_bps, forfeit_unvested_yield, migrate_fee_bps, raw_amount_out, register_staker, settle_staker_rewards, settle_staker_yield}, events::MigrateStakeEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Move stake from one vault to another without leaving the protocol
+///
+/// Burns LP tokens of the source vault and mints LP tokens of the destination vault
+/// for the oracle-equivalent value, minus the migration fee from `migrate_fee_bps`
+/// plus the oracle uncertainty and protocol fees. No tokens leave the treasury,
+/// so the deficit unstake penalty is not applied.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of LP tokens of the source vault to migrate
+/// * `minimum_lp_out` - minimum amount of destination LP tokens to mint
+/// * `proof_out` - Merkle proof of the signer for an allowlisted destination vault
+#[inline(never)]
+pub fn migrate_stake(ctx: Context<MigrateStakeInstructionAccounts>, amount: u64, minimum_lp_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+    let treasury: Account<'_, Treasury> = ctx.accounts.treasury_pda.clone();
+    let vault_in: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_in;
+    let vault_out: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_out;
+    let staker_in: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda_in;
+    let staker_out: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda_out;
+    let signer_lp_ata_in: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata_in.clone();
+    let signer_lp_ata_out: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata_out.clone();
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&treasury)?;
+    check_distinct_vaults(&vault_in.key(), &vault_out.key())?;
+    check_allowlist(vault_out, &ctx.accounts.signer.key(), &proof_out)?;
+    check_vault_inflow(vault_out)?;
+
+    // Validate Pyth price accounts and check price feed freshness
+    let oracle_in: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_in.clone();
+    let oracle_out: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_out.clone();
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    check_oracle(vault_in, &oracle_in, current_timestamp)?;
+    check_oracle(vault_out, &oracle_out, current_timestamp)?;
+
+    // --- Migration Math ---
+    // LP tokens are redeemable 1:1 for the vault token, so price them as `amount` of it
+    let raw_out = raw_amount_out(
+        amount,
+        ctx.accounts.mint_in.decimals,
+        ctx.accounts.mint_out.decimals,
+        oracle_in.price_message,
+        oracle_out.price_message,
+    )?;
+
+    let oracle_fee = conf_fee_bps(
+        oracle_in.price_message.price,
+        oracle_in.price_message.conf,
+        oracle_out.price_message.price,
+        oracle_out.price_message.conf,
+    );
+    let migrate_fee = migrate_fee_bps(vault_in, vault_out).saturating_add(oracle_fee);
+
+    if migrate_fee + treasury.fee_bps > 10_000 {
+        return Err(OxediumError::FeeExceeds.into());
+    }
+
+    let (lp_amount_out, lp_fee, protocol_fee) = calculate_fee_amount(raw_out, migrate_fee, treasury.fee_bps)?;
+
+    if lp_amount_out < minimum_lp_out {
+        return Err(OxediumError::HighSlippage.into());
+    }
+
+    check_deposit_caps(vault_out, signer_lp_ata_out.amount + staker_out.locked_amount, lp_amount_out, ctx.accounts.mint_out.decimals, Some(&oracle_out.price_message))?;
+
+    // Settle pending yield on both positions before their LP balances change
+    settle_staker_yield(vault_in, staker_in, signer_lp_ata_in.amount, current_timestamp);
+    settle_staker_rewards(vault_in, ctx.accounts.reward_stream_in.as_deref_mut(), staker_in, signer_lp_ata_in.amount, current_timestamp)?;
+
+    // Fees accrue to the destination vault before the new LP is minted
+    accrue_vault_yield(vault_out, lp_fee, current_timestamp);
+    vault_out.protocol_yield += protocol_fee;
+
+    register_staker(vault_out, staker_out, ctx.accounts.signer.key(), ctx.accounts.mint_out.key());
+    settle_staker_yield(vault_out, staker_out, signer_lp_ata_out.amount, current_timestamp);
+    settle_staker_rewards(vault_out, ctx.accounts.reward_stream_out.as_deref_mut(), staker_out, signer_lp_ata_out.amount, current_timestamp)?;
+
+    // Migrated stake is a fresh deposit in the destination vault
+    staker_out.stake_time = current_timestamp;
+
+    // Burn LP tokens of the source vault
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint_in.to_account_info(),
+        from: signer_lp_ata_in.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    // Mint LP tokens of the destination vault
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts: MintTo<'_> = MintTo {
+        mint: ctx.accounts.lp_mint_out.to_account_info(),
+        to: signer_lp_ata_out.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        lp_amount_out,
+    )?;
+
+    // Tokens stay where they are: only the LP claims move between vaults
+    vault_in.initial_liquidity -= amount;
+    vault_out.initial_liquidity += lp_amount_out;
+
+    // Leaving the source vault early forfeits the migrated share of its unvested yield
+    let position: u64 = signer_lp_ata_in.amount + staker_in.locked_amount;
+    forfeit_unvested_yield(vault_in, staker_in, amount, position, current_timestamp);
+
+    emit!(MigrateStakeEvent {
+        user: ctx.accounts.signer.key(),
+        fee_bps: migrate_fee + treasury.fee_bps,
+        token_in: vault_in.token_mint,
+        token_out: vault_out.token_mint,
+        lp_amount_in: amount,
+        lp_amount_out,
+        lp_fee,
+        protocol_fee
+    });
+
+    Ok(())
+}
+
+/// Accounts required for the migrate_stake instruction
+#[derive(Accounts)]
+pub struct MigrateStakeInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker moving the position
+
+    pub mint_in: Account<'info, Mint>,  // token mint of the source vault
+    pub mint_out: Account<'info, Mint>, // token mint of the destination vault
+
+    pub pyth_price_account_in: Account<'info, PriceUpdateV2>, // Pyth price feed for the source vault token
+    pub pyth_price_account_out: Account<'info, PriceUpdateV2>, // Pyth price feed for the destination vault token
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda_in.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint_in: Account<'info, Mint>, // LP token mint of the source vault
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda_out.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint_out: Account<'info, Mint>, // LP token mint of the destination vault
+
+    #[account(mut, token::authority = signer, token::mint = lp_mint_in)]
+    pub signer_lp_ata_in: Account<'info, TokenAccount>, // staker's source LP token account
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_mint_out,
+        associated_token::authority = signer,
+    )]
+    pub signer_lp_ata_out: Account<'info, TokenAccount>, // staker's destination LP token account
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_in.key().as_ref()], bump)]
+    pub vault_pda_in: Account<'info, Vault>, // source vault
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_out.key().as_ref()], bump)]
+    pub vault_pda_out: Account<'info, Vault>, // destination vault
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda_in.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda_in: Account<'info, Staker>, // staker PDA of the source vault
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [STAKER_SEED.as_bytes(), vault_pda_out.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + Staker::INIT_SPACE,
+    )]
+    pub staker_pda_out: Account<'info, Staker>, // staker PDA of the destination vault
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda_in.key().as_ref()], bump)]
+    pub reward_stream_in: Option<Account<'info, RewardStream>>, // source vault reward stream, required if the vault has one
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda_out.key().as_ref()], bump)]
+    pub reward_stream_out: Option<Account<'info, RewardStream>>, // destination vault reward stream, required if the vault has one
+
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA controlling LP mints
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
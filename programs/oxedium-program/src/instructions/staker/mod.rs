@@ -1,7 +1,41 @@
 pub use staking::*;
 pub use unstaking::*;
 pub use claim::*;
+pub use claim_as::*;
+pub use claim_many::*;
+pub use unstake_into::*;
+pub use zap_stake::*;
+pub use migrate_stake::*;
+pub use claim_rewards::*;
+pub use lock_stake::*;
+pub use unlock_stake::*;
+pub use emergency_withdraw::*;
+pub use close_staker::*;
+pub use set_staker_delegate::*;
+pub use stake_position::*;
+pub use claim_position::*;
+pub use compound_position::*;
+pub use unstake_position::*;
+pub use close_position::*;
+pub use claim_position_rewards::*;
 
 pub mod staking;
 pub mod unstaking;
-pub mod claim;
\ No newline at end of file
+pub mod claim;
+pub mod claim_as;
+pub mod claim_many;
+pub mod unstake_into;
+pub mod zap_stake;
+pub mod migrate_stake;
+pub mod claim_rewards;
+pub mod lock_stake;
+pub mod unlock_stake;
+pub mod emergency_withdraw;
+pub mod close_staker;
+pub mod set_staker_delegate;
+pub mod stake_position;
+pub mod claim_position;
+pub mod compound_position;
+pub mod unstake_position;
+pub mod close_position;
+pub mod claim_position_rewards;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Mint, Revoke, Token, TokenAccount};
+
+use crate::{states::{Staker, Treasury}, utils::{MINT_SEED, OXEDIUM_SEED, STAKER_SEED, TREASURY_SEED, VAULT_SEED}};
+
+/// Set or clear the delegate allowed to `claim` and `unstaking` on behalf of the owner
+///
+/// Funds are always sent to the owner. Setting a delegate approves the treasury PDA to
+/// burn the owner's LP tokens, so the program can unstake for the delegate without the
+/// delegate ever being able to move the LP itself; clearing it revokes the approval.
+///
+/// The approval uses the LP token account's single SPL delegate slot with an allowance
+/// of `u64::MAX`. Any later `approve` on that token account by the owner replaces it
+/// and silently breaks unstaking by the delegate; call this again to restore it.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `delegate` - the new delegate, `Pubkey::default()` to clear it
+pub fn set_staker_delegate(ctx: Context<SetStakerDelegateInstructionAccounts>, delegate: Pubkey) -> Result<()> {
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    if delegate == Pubkey::default() {
+        let cpi_accounts = Revoke {
+            source: ctx.accounts.signer_lp_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        };
+        token::revoke(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts))?;
+    } else {
+        let cpi_accounts = Approve {
+            to: ctx.accounts.signer_lp_ata.to_account_info(),
+            delegate: ctx.accounts.treasury_pda.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        };
+        token::approve(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), u64::MAX)?;
+    }
+
+    staker.delegate = delegate;
+
+    msg!("SetStakerDelegate {{owner: {}, vault: {}, delegate: {}}}", staker.owner, staker.vault, staker.delegate);
+
+    Ok(())
+}
+
+/// Accounts context for the set_staker_delegate instruction
+#[derive(Accounts)]
+pub struct SetStakerDelegateInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // owner of the staker position
+
+    pub vault_mint: Account<'info, Mint>, // vault token mint
+
+    #[account(seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub lp_mint: Account<'info, Mint>, // LP token mint controlled by treasury
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_lp_ata: Account<'info, TokenAccount>, // owner's LP token account
+
+    /// CHECK: only used for PDA derivation
+    #[account(seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: UncheckedAccount<'info>, // vault PDA
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>, // staker PDA storing the delegate
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA approved to burn LP for the delegate
+
+    pub token_program: Program<'info, Token>,
+}
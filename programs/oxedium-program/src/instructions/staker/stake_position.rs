@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3, Metadata},
+    token::{self, set_authority, spl_token::instruction::AuthorityType, Mint, MintTo, SetAuthority, Token, TokenAccount},
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{check_allowlist, check_deposit_caps, check_oracle, check_positions_allowed, check_stoptap, check_vault_inflow, register_staker, settle_staker_rewards, settle_staker_yield}, events::StakingEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Stake vault tokens into a new position represented by an NFT
+///
+/// Mints a position NFT to the signer and keeps the minted LP tokens in an escrow owned by
+/// the treasury. The position's `Staker` PDA is derived from the NFT mint, so whoever holds
+/// the NFT can claim, compound or unstake it, and transferring the NFT transfers the whole
+/// position including its unsettled yield. Since positions are transferable, they are not
+/// available on vaults with a per-user deposit cap. Close an emptied position with
+/// `close_position`.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of vault tokens to stake
+/// * `proof` - Merkle proof of the signer, required if the vault is allowlisted
+#[inline(never)]
+pub fn stake_position(ctx: Context<StakePositionInstructionAccounts>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+    check_allowlist(vault, &ctx.accounts.signer.key(), &proof)?;
+    check_vault_inflow(vault)?;
+    check_positions_allowed(vault)?;
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Enforce the vault's deposit caps, valuing the deposit via the oracle if caps are in USD
+    let price_message = match &ctx.accounts.pyth_price_account {
+        Some(oracle) => {
+            check_oracle(vault, oracle, current_timestamp)?;
+            Some(oracle.price_message)
+        }
+        None => None,
+    };
+    check_deposit_caps(vault, 0, amount, ctx.accounts.vault_mint.decimals, price_message.as_ref())?;
+
+    // Transfer the staked vault tokens from signer to treasury
+    let cpi_accounts = token::Transfer {
+        from: ctx.accounts.signer_ata.to_account_info(),
+        to: ctx.accounts.treasury_ata.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Mint LP tokens into the position escrow
+    let cpi_accounts: MintTo<'_> = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.position_escrow.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount,
+    )?;
+
+    // Mint the position NFT to the signer
+    let cpi_accounts: MintTo<'_> = MintTo {
+        mint: ctx.accounts.position_mint.to_account_info(),
+        to: ctx.accounts.signer_position_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        1,
+    )?;
+
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: ctx.accounts.position_metadata.to_account_info(),
+        mint: ctx.accounts.position_mint.to_account_info(),
+        mint_authority: ctx.accounts.treasury_pda.to_account_info(),
+        payer: ctx.accounts.signer.to_account_info(),
+        update_authority: ctx.accounts.treasury_pda.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(ctx.accounts.token_metadata_program.to_account_info(), cpi_accounts, signer_seeds),
+        DataV2 {
+            name: POSITION_NFT_NAME.to_string(),
+            symbol: POSITION_NFT_SYMBOL.to_string(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
+    // Fix the supply at one
+    let cpi_accounts = SetAuthority {
+        current_authority: ctx.accounts.treasury_pda.to_account_info(),
+        account_or_mint: ctx.accounts.position_mint.to_account_info(),
+    };
+    set_authority(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // The position is owned by its NFT mint
+    register_staker(vault, staker, ctx.accounts.position_mint.key(), ctx.accounts.vault_mint.key());
+
+    // Start the position at the current checkpoints
+    settle_staker_yield(vault, staker, 0, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, 0, current_timestamp)?;
+    staker.stake_time = current_timestamp;
+
+    // Update vault liquidity accounting
+    vault.initial_liquidity += amount;
+    vault.current_liquidity += amount;
+
+    msg!("StakePosition {{position_mint: {}, mint: {}, amount: {}}}", ctx.accounts.position_mint.key(), vault.token_mint, amount);
+
+    emit!(StakingEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint.key(),
+        amount
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the stake_position instruction
+#[derive(Accounts)]
+pub struct StakePositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // the user staking tokens and receiving the position NFT
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>, // LP token mint controlled by treasury
+
+    #[account(mut, token::authority = signer, token::mint = vault_mint)]
+    pub signer_ata: Box<Account<'info, TokenAccount>>, // user token account for vault token
+
+    #[account(
+        init,
+        payer = signer,
+        mint::decimals = 0,
+        mint::authority = treasury_pda,
+        mint::freeze_authority = treasury_pda,
+    )]
+    pub position_mint: Box<Account<'info, Mint>>, // new position NFT mint, signed by a fresh keypair
+
+    /// CHECK: created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), position_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub position_metadata: UncheckedAccount<'info>, // metadata of the position NFT
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = position_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_position_ata: Box<Account<'info, TokenAccount>>, // signer's position NFT account
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()],
+        bump,
+        token::mint = lp_mint,
+        token::authority = treasury_pda,
+    )]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the position's LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity and yield info
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()],
+        bump,
+        space = 8 + Staker::INIT_SPACE,
+    )]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record, owned by the NFT mint
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    pub pyth_price_account: Option<Box<Account<'info, PriceUpdateV2>>>, // Pyth price feed, required for USD deposit caps
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA controlling the mints
+
+    #[account(
+        mut,
+        associated_token::mint = vault_mint,
+        associated_token::authority = treasury_pda,
+    )]
+    pub treasury_ata: Box<Account<'info, TokenAccount>>, // treasury token account holding staked vault tokens
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
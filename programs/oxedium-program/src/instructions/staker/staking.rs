@@ -1,27 +1,49 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, MintTo, Token, TokenAccount}};
-use crate::{components::{calculate_staker_yield, check_stoptap}, events::StakingEvent, states::{Staker, Treasury, Vault}, utils::*};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{check_allowlist, check_deposit_caps, check_oracle, check_staker_authority, check_stoptap, check_vault_inflow, register_staker, settle_staker_rewards, settle_staker_yield}, events::StakingEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
 
-/// Stake a given amount of vault tokens and mint LP tokens to the staker
+/// Stake a given amount of vault tokens and mint LP tokens to the beneficiary
+///
+/// The signer pays the tokens; the position (LP tokens and Staker PDA) belongs to
+/// `beneficiary`, which may be the signer itself.
+///
+/// Every deposit restarts the position's yield vesting period, so anyone may open a
+/// position for a beneficiary, but only its owner or delegate may add to an existing one.
 ///
 /// # Arguments
 /// * `ctx` - context containing all accounts for staking
 /// * `amount` - amount of vault tokens to stake
+/// * `proof` - Merkle proof of the beneficiary, required if the vault is allowlisted
 #[inline(never)]
-pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64) -> Result<()> {
+pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
     let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
     let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
-    let signer_lp_ata: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata.clone();
+    let beneficiary_lp_ata: Account<'_, TokenAccount> = ctx.accounts.beneficiary_lp_ata.clone();
 
     // Check if the vault is active and stop-tap is not enabled
     check_stoptap(&ctx.accounts.treasury_pda)?;
-
-    // Get the cumulative yield per LP token from the vault
-    let cumulative_yield: u128 = vault.cumulative_yield_per_lp;
-    // Get the staker's current LP token balance
-    let staker_lp: u64 = signer_lp_ata.amount;
-    // Get the last recorded cumulative yield for the staker
-    let last_cumulative_yield: u128 = staker.last_cumulative_yield;
+    check_allowlist(vault, &ctx.accounts.beneficiary.key(), &proof)?;
+    check_vault_inflow(vault)?;
+
+    // Third parties cannot top up (and so restart the vesting of) someone else's position
+    if staker.owner != Pubkey::default() {
+        check_staker_authority(staker, &ctx.accounts.signer.key())?;
+    }
+
+    // Get the beneficiary's current LP token balance
+    let staker_lp: u64 = beneficiary_lp_ata.amount;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Enforce the vault's deposit caps, valuing the deposit via the oracle if caps are in USD
+    let price_message = match &ctx.accounts.pyth_price_account {
+        Some(oracle) => {
+            check_oracle(vault, oracle, current_timestamp)?;
+            Some(oracle.price_message)
+        }
+        None => None,
+    };
+    check_deposit_caps(vault, staker_lp + staker.locked_amount, amount, ctx.accounts.vault_mint.decimals, price_message.as_ref())?;
 
     // Transfer the staked vault tokens from signer to treasury
     let cpi_accounts = token::Transfer {
@@ -36,10 +58,10 @@ pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64) -> Result<
     let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
     let signer_seeds = &[&seeds[..]];
 
-    // Mint LP tokens to the staker corresponding to the staked amount
+    // Mint LP tokens to the beneficiary corresponding to the staked amount
     let cpi_accounts: MintTo<'_> = MintTo {
         mint: ctx.accounts.lp_mint.to_account_info(),
-        to: signer_lp_ata.to_account_info(),
+        to: beneficiary_lp_ata.to_account_info(),
         authority: ctx.accounts.treasury_pda.to_account_info(),
     };
 
@@ -51,19 +73,21 @@ pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64) -> Result<
     token::mint_to(cpi_ctx, amount)?;
 
     // Set staker PDA owner and vault
-    staker.owner = ctx.accounts.signer.key();
-    staker.vault = ctx.accounts.vault_mint.key();
+    register_staker(vault, staker, ctx.accounts.beneficiary.key(), ctx.accounts.vault_mint.key());
 
     // Calculate pending yield for staker and update
-    staker.pending_claim += calculate_staker_yield(cumulative_yield, staker_lp, last_cumulative_yield);
-    staker.last_cumulative_yield = cumulative_yield;
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    // A fresh deposit restarts the yield vesting period
+    staker.stake_time = current_timestamp;
 
     // Update vault liquidity accounting
     vault.initial_liquidity += amount;
     vault.current_liquidity += amount;
 
     emit!(StakingEvent {
-        user: ctx.accounts.signer.key(),
+        user: ctx.accounts.beneficiary.key(),
         mint: vault.token_mint.key(),
         amount: amount
     });
@@ -75,7 +99,10 @@ pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64) -> Result<
 #[derive(Accounts)]
 pub struct StakingInstructionAccounts<'info> {
     #[account(mut)]
-    pub signer: Signer<'info>, // the user staking tokens
+    pub signer: Signer<'info>, // the user paying the staked tokens
+
+    /// CHECK: any wallet or PDA; only used as the owner of the position
+    pub beneficiary: UncheckedAccount<'info>, // owner of the LP tokens and Staker PDA
 
     #[account(mut)]
     pub vault_mint: Account<'info, Mint>, // vault token mint
@@ -96,9 +123,9 @@ pub struct StakingInstructionAccounts<'info> {
         init_if_needed,
         payer = signer,
         associated_token::mint = lp_mint,
-        associated_token::authority = signer,
+        associated_token::authority = beneficiary,
     )]
-    pub signer_lp_ata: Account<'info, TokenAccount>, // LP token account for staker
+    pub beneficiary_lp_ata: Account<'info, TokenAccount>, // LP token account of the beneficiary
 
     #[account(mut, seeds = [VAULT_SEED.as_bytes(), &vault_mint.to_account_info().key.to_bytes()], bump)]
     pub vault_pda: Account<'info, Vault>, // vault PDA storing liquidity and yield info
@@ -106,12 +133,17 @@ pub struct StakingInstructionAccounts<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()],
+        seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), beneficiary.key().as_ref()],
         bump,
-        space = 8 + 32 + 32 + 16 + 8,
+        space = 8 + Staker::INIT_SPACE,
     )]
     pub staker_pda: Account<'info, Staker>, // staker PDA storing pending rewards and last yield
 
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    pub pyth_price_account: Option<Account<'info, PriceUpdateV2>>, // Pyth price feed, required for USD deposit caps
+
     #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury_pda: Account<'info, Treasury>, // treasury PDA controlling LP mint
 
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, burn, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::{components::{boost_weight, check_stoptap, settle_staker_rewards, settle_staker_yield}, events::UnlockEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Release locked LP tokens back to the staker
+///
+/// Unlocking before `lock_end` burns `EARLY_UNLOCK_PENALTY_BPS` of the locked LP.
+/// The burned LP's underlying tokens are paid out as yield to the other non-locked
+/// stakers, or to the protocol if there are none.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts for unlocking
+#[inline(never)]
+pub fn unlock_stake(ctx: Context<UnlockStakeInstructionAccounts>) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    require!(staker.locked_amount > 0, OxediumError::NoActiveLock);
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    // Settle with the boosted weight up to now
+    let staker_lp: u64 = ctx.accounts.signer_lp_ata.amount;
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    let locked_amount: u64 = staker.locked_amount;
+    let penalty: u64 = if current_timestamp < staker.lock_end {
+        (locked_amount as u128 * EARLY_UNLOCK_PENALTY_BPS as u128 / 10_000) as u64
+    } else {
+        0
+    };
+    let unlocked_amount: u64 = locked_amount - penalty;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Return the locked LP tokens to the staker
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lock_escrow.to_account_info(),
+        to: ctx.accounts.signer_lp_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        unlocked_amount,
+    )?;
+
+    // Remove the lock and its boosted weight
+    vault.total_locked -= locked_amount;
+    vault.total_boost_weight -= boost_weight(locked_amount, staker.boost_bps);
+
+    staker.locked_amount = 0;
+    staker.lock_end = 0;
+    staker.boost_bps = 0;
+
+    if penalty > 0 {
+        // Burn the penalty LP tokens from the escrow
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            from: ctx.accounts.lock_escrow.to_account_info(),
+            authority: ctx.accounts.treasury_pda.to_account_info(),
+        };
+        burn(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+            penalty,
+        )?;
+
+        vault.initial_liquidity -= penalty;
+
+        // Pay the underlying tokens out to the other liquid (non-locked) LP holders;
+        // the unlocking staker's own LP is excluded so none of the penalty flows back
+        let own_lp: u64 = staker_lp + unlocked_amount;
+        let liquid_lp: u64 = vault.initial_liquidity - vault.total_locked - own_lp;
+        if liquid_lp > 0 {
+            vault.cumulative_penalty_per_lp += (penalty as u128 * SCALE) / liquid_lp as u128;
+            vault.unclaimed_yield += penalty;
+        } else {
+            // Nobody else to pay: the penalty goes to the protocol
+            vault.protocol_yield += penalty;
+        }
+        staker.last_cumulative_penalty = vault.cumulative_penalty_per_lp;
+    }
+
+    emit!(UnlockEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint,
+        amount: unlocked_amount,
+        penalty
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the unlock_stake instruction
+#[derive(Accounts)]
+pub struct UnlockStakeInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // the staker unlocking LP tokens
+
+    pub vault_mint: Account<'info, Mint>, // vault token mint
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>, // LP token mint controlled by treasury
+
+    #[account(mut, token::authority = signer, token::mint = lp_mint)]
+    pub signer_lp_ata: Account<'info, TokenAccount>, // staker's LP token account
+
+    #[account(mut, seeds = [LOCK_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub lock_escrow: Account<'info, TokenAccount>, // escrow holding the locked LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Account<'info, Vault>, // vault PDA storing liquidity and yield info
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>, // staker PDA storing the lock
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA, authority of the lock escrow
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, burn, Burn, Mint, Token, TokenAccount, Transfer}};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{accrue_vault_yield, check_allowlist, check_oracle, check_stoptap, check_vault_outflow, compute_swap_math, forfeit_unvested_yield, settle_staker_rewards, settle_staker_yield}, events::UnstakeIntoEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Burn LP tokens of one vault and receive the oracle-equivalent value in another vault's token
+///
+/// Lets LPs of a drained (deficit) vault exit into a vault that holds surplus liquidity.
+/// Pricing and fees go through `compute_swap_math`, so the fee stays at the output vault's
+/// base fee when it holds relatively more liquidity than the staked vault, and grows with
+/// the output vault's deficit otherwise. The deficit unstake penalty is not applied.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of LP tokens of the staked vault to burn
+/// * `minimum_out` - minimum amount of output tokens
+/// * `proof_out` - Merkle proof of the signer for an allowlisted output vault
+#[inline(never)]
+pub fn unstake_into(ctx: Context<UnstakeIntoInstructionAccounts>, amount: u64, minimum_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+    let treasury: Account<'_, Treasury> = ctx.accounts.treasury_pda.clone();
+    let vault_in: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_in;
+    let vault_out: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_out;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+    let signer_lp_ata: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata.clone();
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&treasury)?;
+    check_allowlist(vault_out, &ctx.accounts.signer.key(), &proof_out)?;
+    check_vault_outflow(vault_out)?;
+
+    // Validate Pyth price accounts and check price feed freshness
+    let oracle_in: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_in.clone();
+    let oracle_out: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_out.clone();
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    check_oracle(vault_in, &oracle_in, current_timestamp)?;
+    check_oracle(vault_out, &oracle_out, current_timestamp)?;
+
+    // LP tokens are redeemable 1:1 for the staked vault token, so price them as `amount` of it
+    let result = compute_swap_math(
+        amount,
+        oracle_in.price_message,
+        oracle_out.price_message,
+        ctx.accounts.mint_in.decimals,
+        ctx.accounts.mint_out.decimals,
+        vault_in,
+        vault_out,
+        &treasury,
+    )?;
+
+    if result.net_amount_out < minimum_out {
+        return Err(OxediumError::HighSlippage.into());
+    }
+
+    // Settle pending yield on the LP balance before it is burned
+    settle_staker_yield(vault_in, staker, signer_lp_ata.amount, current_timestamp);
+    settle_staker_rewards(vault_in, ctx.accounts.reward_stream_in.as_deref_mut(), staker, signer_lp_ata.amount, current_timestamp)?;
+
+    // Burn LP tokens of the staked vault
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: signer_lp_ata.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    // Transfer output tokens from treasury to staker
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_ata_out.to_account_info(),
+        to: ctx.accounts.signer_ata_out.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info()
+    };
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds),
+        result.net_amount_out)?;
+
+    // The staked vault keeps its tokens but owes fewer LP tokens,
+    // the output vault pays out and earns the fees
+    vault_in.initial_liquidity -= amount;
+    let position: u64 = signer_lp_ata.amount + staker.locked_amount;
+    forfeit_unvested_yield(vault_in, staker, amount, position, current_timestamp);
+    vault_out.current_liquidity -= result.net_amount_out;
+    accrue_vault_yield(vault_out, result.lp_fee_amount, current_timestamp);
+    vault_out.protocol_yield += result.protocol_fee_amount;
+
+    emit!(UnstakeIntoEvent {
+        user: ctx.accounts.signer.key(),
+        fee_bps: result.swap_fee_bps + treasury.fee_bps,
+        token_in: vault_in.token_mint,
+        token_out: vault_out.token_mint,
+        lp_amount: amount,
+        amount_out: result.net_amount_out,
+        lp_fee: result.lp_fee_amount,
+        protocol_fee: result.protocol_fee_amount
+    });
+
+    Ok(())
+}
+
+/// Accounts required for the unstake_into instruction
+#[derive(Accounts)]
+pub struct UnstakeIntoInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // staker exiting the vault
+
+    pub mint_in: Account<'info, Mint>,  // token mint of the staked vault
+    pub mint_out: Account<'info, Mint>, // token mint of the vault paying out
+
+    pub pyth_price_account_in: Account<'info, PriceUpdateV2>, // Pyth price feed for the staked vault token
+    pub pyth_price_account_out: Account<'info, PriceUpdateV2>, // Pyth price feed for the output token
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda_in.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>, // LP token mint of the staked vault
+
+    #[account(mut, token::authority = signer, token::mint = lp_mint)]
+    pub signer_lp_ata: Account<'info, TokenAccount>, // staker's LP token account
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint_out,
+        associated_token::authority = signer,
+    )]
+    pub signer_ata_out: Account<'info, TokenAccount>, // staker's output token account
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_in.key().as_ref()], bump)]
+    pub vault_pda_in: Account<'info, Vault>, // staked vault
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_out.key().as_ref()], bump)]
+    pub vault_pda_out: Account<'info, Vault>, // output vault
+
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda_in.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker_pda: Account<'info, Staker>, // staker PDA of the staked vault
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda_in.key().as_ref()], bump)]
+    pub reward_stream_in: Option<Account<'info, RewardStream>>, // staked vault reward stream, required if the vault has one
+
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA
+
+    #[account(mut, token::authority = treasury_pda, token::mint = mint_out)]
+    pub treasury_ata_out: Account<'info, TokenAccount>, // treasury output token account
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
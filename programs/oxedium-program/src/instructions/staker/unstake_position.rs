@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, burn, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::{components::{calculate_fee_amount, check_stoptap, forfeit_unvested_yield, settle_staker_rewards, settle_staker_yield, unstake_fee_bps}, events::UnstakingEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Unstake LP tokens of an NFT position and withdraw the underlying vault tokens
+///
+/// Callable by the current holder of the position NFT. Applies the same extra fee as
+/// `unstaking` for drained vaults. The NFT keeps representing the remaining position.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount` - amount of LP tokens to unstake from the position
+#[inline(never)]
+pub fn unstake_position(ctx: Context<UnstakePositionInstructionAccounts>, amount: u64) -> Result<()> {
+    let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+
+    check_stoptap(&ctx.accounts.treasury_pda)?;
+
+    let staker_lp: u64 = ctx.accounts.position_escrow.amount;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    settle_staker_yield(vault, staker, staker_lp, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, staker_lp, current_timestamp)?;
+
+    let extra_fee_bps: u64 = unstake_fee_bps(vault);
+    let unstake_amount: u64 = calculate_fee_amount(amount, extra_fee_bps, 0)?.0;
+
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    // Burn LP tokens from the position escrow
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.position_escrow.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    burn(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds), amount)?;
+
+    // Transfer unstake amount from treasury to the recipient
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_ata.to_account_info(),
+        to: ctx.accounts.recipient_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        unstake_amount,
+    )?;
+
+    // Update vault liquidity
+    vault.initial_liquidity -= amount;
+    vault.current_liquidity -= unstake_amount;
+
+    // Exiting before the minimum stake age returns the exiting share of unvested yield
+    // to the remaining stakers
+    forfeit_unvested_yield(vault, staker, amount, staker_lp, current_timestamp);
+
+    emit!(UnstakingEvent {
+        user: ctx.accounts.signer.key(),
+        mint: vault.token_mint.key(),
+        amount: unstake_amount,
+        extra_fee_bps
+    });
+
+    Ok(())
+}
+
+/// Accounts context for the unstake_position instruction
+#[derive(Accounts)]
+pub struct UnstakePositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // holder of the position NFT
+
+    pub vault_mint: Box<Account<'info, Mint>>, // vault token mint
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>, // LP token mint controlled by treasury
+
+    pub position_mint: Box<Account<'info, Mint>>, // position NFT mint
+
+    #[account(
+        token::mint = position_mint,
+        token::authority = signer,
+        constraint = holder_position_ata.amount == 1 @ OxediumError::NotPositionHolder
+    )]
+    pub holder_position_ata: Box<Account<'info, TokenAccount>>, // holder's position NFT account
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), staker_pda.key().as_ref()], bump)]
+    pub position_escrow: Box<Account<'info, TokenAccount>>, // escrow holding the position's LP tokens
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), vault_mint.key().as_ref()], bump)]
+    pub vault_pda: Box<Account<'info, Vault>>, // vault PDA storing liquidity and yield info
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), vault_pda.key().as_ref(), position_mint.key().as_ref()], bump)]
+    pub staker_pda: Box<Account<'info, Staker>>, // position record
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>, // vault reward stream, required if the vault has one
+
+    #[account(seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Box<Account<'info, Treasury>>, // treasury PDA
+
+    #[account(
+        mut,
+        associated_token::mint = vault_mint,
+        associated_token::authority = treasury_pda,
+    )]
+    pub treasury_ata: Box<Account<'info, TokenAccount>>, // treasury token account holding vault tokens
+
+    #[account(mut, token::mint = vault_mint)]
+    pub recipient_ata: Box<Account<'info, TokenAccount>>, // token account receiving the vault tokens
+
+    pub token_program: Program<'info, Token>,
+}
@@ -1,52 +1,53 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::{self, burn, Burn, Mint, Token, TokenAccount, Transfer}};
-use crate::{components::{calculate_fee_amount, calculate_staker_yield, check_stoptap}, events::UnstakingEvent, states::{Staker, Treasury, Vault}, utils::*};
+use crate::{components::{calculate_fee_amount, check_staker_authority, check_stoptap, forfeit_unvested_yield, settle_staker_rewards, settle_staker_yield, unstake_fee_bps}, events::UnstakingEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
 
+/// Burn LP tokens and withdraw the underlying vault tokens
+///
+/// Can be called by the position owner or its delegate; the tokens are always sent to the owner.
+/// A delegate burns through the treasury PDA, which the owner approves in `set_staker_delegate`.
 #[inline(never)]
 pub fn unstaking(ctx: Context<UnstakingInstructionAccounts>, amount: u64) -> Result<()> {
     let vault: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda;
     let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
-    let signer_lp_ata: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata.clone();
+    let owner_lp_ata: Account<'_, TokenAccount> = ctx.accounts.owner_lp_ata.clone();
 
     // Check if the vault is active and stop-tap is not enabled
     check_stoptap(&ctx.accounts.treasury_pda)?;
-
-    let cumulative_yield: u128 = vault.cumulative_yield_per_lp;
-    let last_cumulative_yield: u128 = staker.last_cumulative_yield;
+    check_staker_authority(staker, &ctx.accounts.signer.key())?;
 
     // --- Dynamic Fee Logic ---
     let mut unstake_amount = amount;
-    let liquidity_ratio = (vault.current_liquidity as u128 * 100) / vault.initial_liquidity as u128; // in %
-    let mut extra_fee_bps: u64 = 0;
-
-    // Apply extra fee if current liquidity < 50%
-    if liquidity_ratio < 50 {
-        extra_fee_bps = 200; // 2% extra fee if liquidity too low
-    }
+    let extra_fee_bps: u64 = unstake_fee_bps(vault);
 
     if extra_fee_bps > 0 {
         unstake_amount = calculate_fee_amount(unstake_amount, extra_fee_bps, 0)?.0;
     }
 
-    // Burn LP tokens
-    let cpi_accounts = Burn {
-        mint: ctx.accounts.lp_mint.to_account_info(),
-        from: signer_lp_ata.to_account_info(),
-        authority: ctx.accounts.signer.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-    );
-    burn(cpi_ctx, amount)?;
-
-    // Transfer unstake amount from treasury to staker
     let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
     let signer_seeds = &[&seeds[..]];
 
+    // Burn LP tokens: the owner signs directly, a delegate through the approved treasury PDA
+    if ctx.accounts.signer.key() == staker.owner {
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            from: owner_lp_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        };
+        burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+    } else {
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            from: owner_lp_ata.to_account_info(),
+            authority: ctx.accounts.treasury_pda.to_account_info(),
+        };
+        burn(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds), amount)?;
+    }
+
+    // Transfer unstake amount from treasury to the staker owner
     let cpi_accounts = Transfer {
         from: ctx.accounts.treasury_ata.to_account_info(),
-        to: ctx.accounts.signer_ata.to_account_info(), 
+        to: ctx.accounts.owner_ata.to_account_info(), 
         authority: ctx.accounts.treasury_pda.to_account_info()
     };
 
@@ -58,15 +59,21 @@ pub fn unstaking(ctx: Context<UnstakingInstructionAccounts>, amount: u64) -> Res
         unstake_amount)?;
 
     // Update pending yield for the staker
-    staker.pending_claim += calculate_staker_yield(cumulative_yield, signer_lp_ata.amount, last_cumulative_yield);
-    staker.last_cumulative_yield = cumulative_yield;
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+    settle_staker_yield(vault, staker, owner_lp_ata.amount, current_timestamp);
+    settle_staker_rewards(vault, ctx.accounts.reward_stream.as_deref_mut(), staker, owner_lp_ata.amount, current_timestamp)?;
 
     // Update vault liquidity
     vault.initial_liquidity -= amount;
     vault.current_liquidity -= unstake_amount;
 
+    // Exiting before the minimum stake age returns the exiting share of unvested yield
+    // to the remaining stakers
+    let position: u64 = owner_lp_ata.amount + staker.locked_amount;
+    forfeit_unvested_yield(vault, staker, amount, position, current_timestamp);
+
     emit!(UnstakingEvent {
-        user: ctx.accounts.signer.key(),
+        user: staker.owner,
         mint: vault.token_mint.key(),
         amount: unstake_amount,
         extra_fee_bps: extra_fee_bps
@@ -79,7 +86,10 @@ pub fn unstaking(ctx: Context<UnstakingInstructionAccounts>, amount: u64) -> Res
 #[derive(Accounts)]
 pub struct UnstakingInstructionAccounts<'info> {
     #[account(mut)]
-    pub signer: Signer<'info>,
+    pub signer: Signer<'info>, // staker owner or its delegate
+
+    /// CHECK: owner of the staker position, only used for PDA derivation and token account authority checks
+    pub owner: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub token_mint: Account<'info, Mint>,
@@ -93,18 +103,21 @@ pub struct UnstakingInstructionAccounts<'info> {
     )]
     pub lp_mint: Account<'info, Mint>,
 
-    #[account(mut, token::authority = signer, token::mint = token_mint)]
-    pub signer_ata: Account<'info, TokenAccount>,
+    #[account(mut, token::authority = owner, token::mint = token_mint)]
+    pub owner_ata: Account<'info, TokenAccount>,
 
-    #[account(mut, token::authority = signer, token::mint = lp_mint)]
-    pub signer_lp_ata: Account<'info, TokenAccount>,
+    #[account(mut, token::authority = owner, token::mint = lp_mint)]
+    pub owner_lp_ata: Account<'info, TokenAccount>,
 
     #[account(mut, seeds = [VAULT_SEED.as_bytes(), &token_mint.to_account_info().key.to_bytes()], bump)]
     pub vault_pda: Account<'info, Vault>,
 
-    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    #[account(mut, seeds = [STAKER_SEED.as_bytes(), vault_pda.key().as_ref(), owner.key().as_ref()], bump)]
     pub staker_pda: Account<'info, Staker>,
 
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda.key().as_ref()], bump)]
+    pub reward_stream: Option<Account<'info, RewardStream>>,
+
     #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury_pda: Account<'info, Treasury>,
 
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, MintTo, Token, TokenAccount}};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::{components::{accrue_vault_yield, check_allowlist, check_deposit_caps, check_oracle, check_stoptap, check_vault_inflow, compute_swap_math, register_staker, settle_staker_rewards, settle_staker_yield}, events::ZapStakeEvent, states::{RewardStream, Staker, Treasury, Vault}, utils::*};
+
+/// Stake into a vault using a different token
+///
+/// The input token is swapped into the target vault's token through `compute_swap_math`
+/// (regular swap fees apply and accrue to the target vault), and the swapped amount is
+/// staked, minting LP tokens of the target vault 1:1. The swapped tokens never leave the treasury.
+///
+/// # Arguments
+/// * `ctx` - context containing all accounts
+/// * `amount_in` - amount of input tokens from user
+/// * `minimum_lp_out` - minimum amount of LP tokens to mint
+/// * `proof_in` - Merkle proof of the signer for an allowlisted input vault
+/// * `proof_out` - Merkle proof of the signer for an allowlisted target vault
+#[inline(never)]
+pub fn zap_stake(
+    ctx: Context<ZapStakeInstructionAccounts>,
+    amount_in: u64,
+    minimum_lp_out: u64,
+    proof_in: Vec<[u8; 32]>,
+    proof_out: Vec<[u8; 32]>,
+) -> Result<()> {
+    let treasury: Account<'_, Treasury> = ctx.accounts.treasury_pda.clone();
+    let vault_in: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_in;
+    let vault_out: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_out;
+    let staker: &mut Account<'_, Staker> = &mut ctx.accounts.staker_pda;
+    let signer_lp_ata: Account<'_, TokenAccount> = ctx.accounts.signer_lp_ata.clone();
+
+    // Check if stop-tap is not enabled
+    check_stoptap(&treasury)?;
+    check_allowlist(vault_in, &ctx.accounts.signer.key(), &proof_in)?;
+    check_allowlist(vault_out, &ctx.accounts.signer.key(), &proof_out)?;
+    check_vault_inflow(vault_in)?;
+    check_vault_inflow(vault_out)?;
+
+    // Validate Pyth price accounts and check price feed freshness
+    let oracle_in: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_in.clone();
+    let oracle_out: Account<'_, PriceUpdateV2> = ctx.accounts.pyth_price_account_out.clone();
+
+    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+
+    check_oracle(vault_in, &oracle_in, current_timestamp)?;
+    check_oracle(vault_out, &oracle_out, current_timestamp)?;
+
+    // Swap the input token into the target vault's token
+    let result = compute_swap_math(
+        amount_in,
+        oracle_in.price_message,
+        oracle_out.price_message,
+        ctx.accounts.mint_in.decimals,
+        ctx.accounts.mint_out.decimals,
+        vault_in,
+        vault_out,
+        &treasury,
+    )?;
+
+    // LP tokens are minted 1:1 with the staked amount
+    let lp_amount: u64 = result.net_amount_out;
+
+    if lp_amount < minimum_lp_out {
+        return Err(OxediumError::HighSlippage.into());
+    }
+
+    // Transfer input tokens from user to treasury
+    let cpi_accounts = token::Transfer {
+        from: ctx.accounts.signer_ata_in.to_account_info(),
+        to: ctx.accounts.treasury_ata_in.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount_in)?;
+
+    // Swap leg: fees accrue to the target vault before the new LP is minted
+    vault_in.current_liquidity += amount_in;
+    accrue_vault_yield(vault_out, result.lp_fee_amount, current_timestamp);
+    vault_out.protocol_yield += result.protocol_fee_amount;
+
+    // Set staker PDA owner and vault
+    register_staker(vault_out, staker, ctx.accounts.signer.key(), ctx.accounts.mint_out.key());
+
+    check_deposit_caps(vault_out, signer_lp_ata.amount + staker.locked_amount, lp_amount, ctx.accounts.mint_out.decimals, Some(&oracle_out.price_message))?;
+
+    // Settle pending yield on the LP balance before minting
+    settle_staker_yield(vault_out, staker, signer_lp_ata.amount, current_timestamp);
+    settle_staker_rewards(vault_out, ctx.accounts.reward_stream_out.as_deref_mut(), staker, signer_lp_ata.amount, current_timestamp)?;
+
+    // A fresh deposit restarts the yield vesting period
+    staker.stake_time = current_timestamp;
+
+    // Mint LP tokens of the target vault to the staker
+    let seeds = &[OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury_pda]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts: MintTo<'_> = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: signer_lp_ata.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds),
+        lp_amount,
+    )?;
+
+    // Stake leg: the swapped tokens stay in the treasury, so current liquidity
+    // of the target vault is unchanged and only its LP supply grows
+    vault_out.initial_liquidity += lp_amount;
+
+    emit!(ZapStakeEvent {
+        user: ctx.accounts.signer.key(),
+        fee_bps: result.swap_fee_bps + treasury.fee_bps,
+        token_in: vault_in.token_mint,
+        token_out: vault_out.token_mint,
+        amount_in,
+        lp_amount,
+        lp_fee: result.lp_fee_amount,
+        protocol_fee: result.protocol_fee_amount
+    });
+
+    Ok(())
+}
+
+/// Accounts required for the zap_stake instruction
+#[derive(Accounts)]
+pub struct ZapStakeInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // the user staking tokens
+
+    pub mint_in: Account<'info, Mint>,  // input token mint
+    pub mint_out: Account<'info, Mint>, // token mint of the vault to stake into
+
+    pub pyth_price_account_in: Account<'info, PriceUpdateV2>, // Pyth price feed for input token
+    pub pyth_price_account_out: Account<'info, PriceUpdateV2>, // Pyth price feed for the staked token
+
+    #[account(mut, token::authority = signer, token::mint = mint_in)]
+    pub signer_ata_in: Account<'info, TokenAccount>, // user's input token account
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED.as_bytes(), vault_pda_out.key().as_ref()],
+        bump,
+        mint::authority = treasury_pda.key(),
+        mint::freeze_authority = treasury_pda.key()
+    )]
+    pub lp_mint: Account<'info, Mint>, // LP token mint of the target vault
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_lp_ata: Account<'info, TokenAccount>, // LP token account for staker
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_in.key().as_ref()], bump)]
+    pub vault_pda_in: Account<'info, Vault>, // input vault
+
+    #[account(mut, seeds = [VAULT_SEED.as_bytes(), mint_out.key().as_ref()], bump)]
+    pub vault_pda_out: Account<'info, Vault>, // target vault
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [STAKER_SEED.as_bytes(), vault_pda_out.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + Staker::INIT_SPACE,
+    )]
+    pub staker_pda: Account<'info, Staker>, // staker PDA of the target vault
+
+    #[account(mut, seeds = [REWARD_STREAM_SEED.as_bytes(), vault_pda_out.key().as_ref()], bump)]
+    pub reward_stream_out: Option<Account<'info, RewardStream>>, // target vault reward stream, required if the vault has one
+
+    #[account(mut, seeds = [OXEDIUM_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury_pda: Account<'info, Treasury>, // treasury PDA controlling LP mint
+
+    #[account(mut, token::authority = treasury_pda, token::mint = mint_in)]
+    pub treasury_ata_in: Account<'info, TokenAccount>, // treasury input token account
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -7,11 +7,11 @@ use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
     components::{
-        check_stoptap, compute_swap_math,
+        apply_swap_result, check_allowlist, check_oracle, check_stoptap, check_vault_inflow, check_vault_outflow, compute_swap_math,
     },
     events::SwapEvent,
     states::{Treasury, Vault},
-    utils::{OxediumError, OXEDIUM_SEED, SCALE, TREASURY_SEED, VAULT_SEED},
+    utils::{OxediumError, OXEDIUM_SEED, TREASURY_SEED, VAULT_SEED},
 };
 
 /// Swap tokens from one vault to another, optionally in quote-only mode
@@ -20,59 +20,37 @@ use crate::{
 /// * `ctx` - context containing all accounts
 /// * `amount_in` - amount of input tokens from user
 /// * `minimum_out` - minimum amount output
+/// * `proof_in` - Merkle proof of the signer for an allowlisted input vault
+/// * `proof_out` - Merkle proof of the signer for an allowlisted output vault
 pub fn swap(
     ctx: Context<SwapInstructionAccounts>,
     amount_in: u64,
     minimum_out: u64,
+    proof_in: Vec<[u8; 32]>,
+    proof_out: Vec<[u8; 32]>,
 ) -> Result<()> {
     let treasury: Account<'_, Treasury> = ctx.accounts.treasury_pda.clone();
     let vault_in: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_in;
     let vault_out: &mut Account<'_, Vault> = &mut ctx.accounts.vault_pda_out;
 
     check_stoptap(&treasury)?;
+    check_allowlist(vault_in, &ctx.accounts.signer.key(), &proof_in)?;
+    check_allowlist(vault_out, &ctx.accounts.signer.key(), &proof_out)?;
+    check_vault_inflow(vault_in)?;
+    check_vault_outflow(vault_out)?;
 
-    // === 2. Validate Pyth price accounts ===
-    if ctx.accounts.pyth_price_account_in.key() != vault_in.pyth_price_account {
-        return Err(OxediumError::InvalidPythAccount.into());
-    }
-    if ctx.accounts.pyth_price_account_out.key() != vault_out.pyth_price_account {
-        return Err(OxediumError::InvalidPythAccount.into());
-    }
-
-    // === 3. Read prices from Pyth ===
+    // === 2. Read prices from Pyth ===
     let oracle_in: Account<'_, PriceUpdateV2>  = ctx.accounts.pyth_price_account_in.clone();
     let oracle_out: Account<'_, PriceUpdateV2>  = ctx.accounts.pyth_price_account_out.clone();
 
-    // === 4. Check price feed freshness ===
+    // === 3. Validate Pyth price accounts and check price feed freshness ===
     let clock: Clock = Clock::get()?;
     let current_timestamp: i64 = clock.unix_timestamp;
 
-    let max_age_vault_in = current_timestamp
-        - ctx
-            .accounts
-            .pyth_price_account_in
-            .price_message
-            .publish_time;
-    let max_age_vault_out = current_timestamp
-        - ctx
-            .accounts
-            .pyth_price_account_out
-            .price_message
-            .publish_time;
-
-    if max_age_vault_in > vault_in.max_age_price as i64 {
-        msg!("Vault In: Price feed stale by {} seconds", max_age_vault_in);
-        return Err(OxediumError::OracleDataTooOld.into());
-    }
-    if max_age_vault_out > vault_out.max_age_price as i64 {
-        msg!(
-            "Vault Out: Price feed stale by {} seconds",
-            max_age_vault_out
-        );
-        return Err(OxediumError::OracleDataTooOld.into());
-    }
+    check_oracle(vault_in, &oracle_in, current_timestamp)?;
+    check_oracle(vault_out, &oracle_out, current_timestamp)?;
 
-    // === 5. Compute swap math ===
+    // === 4. Compute swap math ===
     let result = compute_swap_math(
         amount_in,
         oracle_in.price_message,
@@ -88,13 +66,10 @@ pub fn swap(
         return Err(OxediumError::HighSlippage.into());
     }
 
-    // === 6. Update vaults and yields ===
-    vault_in.current_liquidity += amount_in;
-    vault_out.current_liquidity -= result.net_amount_out;
-    vault_out.cumulative_yield_per_lp += (result.lp_fee_amount as u128 * SCALE) / vault_out.initial_liquidity as u128;
-    vault_out.protocol_yield += result.protocol_fee_amount;
+    // === 5. Update vaults and yields ===
+    apply_swap_result(vault_in, vault_out, amount_in, &result, current_timestamp);
 
-    // === 7. Transfer input tokens from user to treasury ===
+    // === 6. Transfer input tokens from user to treasury ===
     let cpi_accounts: token::Transfer<'_> = token::Transfer {
         from: ctx.accounts.signer_ata_in.to_account_info(),
         to: ctx.accounts.treasury_ata_in.to_account_info(),
@@ -105,7 +80,7 @@ pub fn swap(
         amount_in,
     )?;
 
-    // === 8. Transfer output tokens from treasury to user ===
+    // === 7. Transfer output tokens from treasury to user ===
     let seeds: &[&[u8]; 3] = &[
         OXEDIUM_SEED.as_bytes(),
         TREASURY_SEED.as_bytes(),
@@ -127,7 +102,7 @@ pub fn swap(
         result.net_amount_out,
     )?;
 
-    // === 9. Emit swap event for off-chain indexing ===
+    // === 8. Emit swap event for off-chain indexing ===
     emit!(SwapEvent {
         user: ctx.accounts.signer.key(),
         fee_bps: result.swap_fee_bps + treasury.fee_bps,
@@ -5,6 +5,7 @@ use anchor_lang::prelude::*;
 use instructions::admin::*;
 use instructions::staker::*;
 use instructions::trader::*;
+use states::{FeeRecipient, ParameterChange, ProposalAccount, Role, VaultStatus};
 
 pub mod states;
 pub mod instructions;
@@ -19,41 +20,213 @@ declare_id!("oxe2YhrQZ8yjbaHfp6mQXfSJd124qK2sRpdH9ePZXh1");
 pub mod oxedium_program {
     use super::*;
 
-    pub fn init_treasury(ctx: Context<InitTreasuryInstructionAccounts>, proto_fee: u64) -> Result<()> {
-        instructions::admin::init_treasury(ctx, proto_fee)
+    pub fn init_treasury(ctx: Context<InitTreasuryInstructionAccounts>, proto_fee: u64, deviation: u64, stoptap: bool) -> Result<()> {
+        instructions::admin::init_treasury(ctx, proto_fee, deviation, stoptap)
     }
 
-    pub fn update_treasury(ctx: Context<UpdateTreasuryInstructionAccounts>, stoptap: bool, proto_fee: u64, deviation: u64) -> Result<()> {
-        instructions::admin::update_treasury(ctx, stoptap, proto_fee, deviation)
+    pub fn update_treasury(ctx: Context<UpdateTreasuryInstructionAccounts>, proto_fee: u64, deviation: u64) -> Result<()> {
+        instructions::admin::update_treasury(ctx, proto_fee, deviation)
+    }
+
+    pub fn set_stoptap(ctx: Context<SetStoptapInstructionAccounts>, stoptap: bool) -> Result<()> {
+        instructions::admin::set_stoptap(ctx, stoptap)
     }
 
     pub fn init_vault(ctx: Context<InitVaultInstructionAccounts>, base_fee: u64, max_age_price: u64) -> Result<()> {
         instructions::admin::init_vault(ctx, base_fee, max_age_price)
     }
 
-    pub fn update_vault(ctx: Context<UpdateVaultInstructionAccounts>, base_fee: u64, max_age_price: u64) -> Result<()> {
-        instructions::admin::update_vault(ctx, base_fee, max_age_price)
+    pub fn update_vault(ctx: Context<UpdateVaultInstructionAccounts>, base_fee: u64, max_age_price: u64, min_stake_age: i64, yield_drip_window: i64, deposit_caps: DepositCaps) -> Result<()> {
+        instructions::admin::update_vault(ctx, base_fee, max_age_price, min_stake_age, yield_drip_window, deposit_caps)
     }
 
-    pub fn collect(ctx: Context<CollectInstructionAccounts>) -> Result<()> {
+    pub fn collect<'info>(ctx: Context<'_, '_, 'info, 'info, CollectInstructionAccounts<'info>>) -> Result<()> {
         instructions::admin::collect(ctx)
     }
 
+    pub fn set_fee_config(ctx: Context<SetFeeConfigInstructionAccounts>, recipients: Vec<FeeRecipient>) -> Result<()> {
+        instructions::admin::set_fee_config(ctx, recipients)
+    }
+
+    pub fn set_parameter_bounds(ctx: Context<SetParameterBoundsInstructionAccounts>, bounds: ParameterBounds) -> Result<()> {
+        instructions::admin::set_parameter_bounds(ctx, bounds)
+    }
+
+    pub fn set_vault_status(ctx: Context<SetVaultStatusInstructionAccounts>, status: VaultStatus) -> Result<()> {
+        instructions::admin::set_vault_status(ctx, status)
+    }
+
+    pub fn close_vault(ctx: Context<CloseVaultInstructionAccounts>) -> Result<()> {
+        instructions::admin::close_vault(ctx)
+    }
+
+    pub fn migrate_treasury(ctx: Context<MigrateTreasuryInstructionAccounts>) -> Result<()> {
+        instructions::admin::migrate_treasury(ctx)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVaultInstructionAccounts>) -> Result<()> {
+        instructions::admin::migrate_vault(ctx)
+    }
+
+    pub fn migrate_staker(ctx: Context<MigrateStakerInstructionAccounts>) -> Result<()> {
+        instructions::admin::migrate_staker(ctx)
+    }
+
+    pub fn create_reward_stream(ctx: Context<CreateRewardStreamInstructionAccounts>, emission_per_second: u64, start_time: i64, end_time: i64) -> Result<()> {
+        instructions::admin::create_reward_stream(ctx, emission_per_second, start_time, end_time)
+    }
+
+    pub fn fund_reward_stream(ctx: Context<FundRewardStreamInstructionAccounts>, amount: u64) -> Result<()> {
+        instructions::admin::fund_reward_stream(ctx, amount)
+    }
+
+    pub fn extend_reward_stream(ctx: Context<ExtendRewardStreamInstructionAccounts>, end_time: i64, emission_per_second: u64) -> Result<()> {
+        instructions::admin::extend_reward_stream(ctx, end_time, emission_per_second)
+    }
+
+    pub fn cancel_reward_stream(ctx: Context<CancelRewardStreamInstructionAccounts>) -> Result<()> {
+        instructions::admin::cancel_reward_stream(ctx)
+    }
+
+    pub fn set_vault_allowlist(ctx: Context<SetVaultAllowlistInstructionAccounts>, enabled: bool, root: [u8; 32]) -> Result<()> {
+        instructions::admin::set_vault_allowlist(ctx, enabled, root)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdminInstructionAccounts>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdminInstructionAccounts>) -> Result<()> {
+        instructions::admin::accept_admin(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposalInstructionAccounts>) -> Result<()> {
+        instructions::admin::cancel_admin_proposal(ctx)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRoleInstructionAccounts>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::admin::grant_role(ctx, role, holder)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRoleInstructionAccounts>, role: Role) -> Result<()> {
+        instructions::admin::revoke_role(ctx, role)
+    }
+
+    pub fn queue_change(ctx: Context<QueueChangeInstructionAccounts>, id: u64, change: ParameterChange) -> Result<()> {
+        instructions::admin::queue_change(ctx, id, change)
+    }
+
+    pub fn execute_change(ctx: Context<ExecuteChangeInstructionAccounts>) -> Result<()> {
+        instructions::admin::execute_change(ctx)
+    }
+
+    pub fn cancel_change(ctx: Context<CancelChangeInstructionAccounts>) -> Result<()> {
+        instructions::admin::cancel_change(ctx)
+    }
+
+    pub fn create_multisig(ctx: Context<CreateMultisigInstructionAccounts>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::admin::create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn change_multisig(ctx: Context<ChangeMultisigInstructionAccounts>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::admin::change_multisig(ctx, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposalInstructionAccounts>, id: u64, data: Vec<u8>, accounts: Vec<ProposalAccount>) -> Result<()> {
+        instructions::admin::create_proposal(ctx, id, data, accounts)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposalInstructionAccounts>) -> Result<()> {
+        instructions::admin::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposalInstructionAccounts<'info>>) -> Result<()> {
+        instructions::admin::execute_proposal(ctx)
+    }
+
     // Staker instructions
-    pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64) -> Result<()> {
-        instructions::staker::staking(ctx, amount)
+    pub fn staking(ctx: Context<StakingInstructionAccounts>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::staking(ctx, amount, proof)
     }
 
     pub fn unstaking(ctx: Context<UnstakingInstructionAccounts>, amount: u64) -> Result<()> {
         instructions::staker::unstaking(ctx, amount)
     }
 
-    pub fn claim(ctx: Context<ClaimInstructionAccounts>) -> Result<()> {
-        instructions::staker::claim(ctx)
+    pub fn claim(ctx: Context<ClaimInstructionAccounts>, amount: Option<u64>) -> Result<()> {
+        instructions::staker::claim(ctx, amount)
+    }
+
+    pub fn claim_as(ctx: Context<ClaimAsInstructionAccounts>, amount: Option<u64>, minimum_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::claim_as(ctx, amount, minimum_out, proof_out)
+    }
+
+    pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimManyInstructionAccounts<'info>>) -> Result<()> {
+        instructions::staker::claim_many(ctx)
+    }
+
+    pub fn unstake_into(ctx: Context<UnstakeIntoInstructionAccounts>, amount: u64, minimum_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::unstake_into(ctx, amount, minimum_out, proof_out)
+    }
+
+    pub fn zap_stake(ctx: Context<ZapStakeInstructionAccounts>, amount_in: u64, minimum_lp_out: u64, proof_in: Vec<[u8; 32]>, proof_out: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::zap_stake(ctx, amount_in, minimum_lp_out, proof_in, proof_out)
+    }
+
+    pub fn migrate_stake(ctx: Context<MigrateStakeInstructionAccounts>, amount: u64, minimum_lp_out: u64, proof_out: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::migrate_stake(ctx, amount, minimum_lp_out, proof_out)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewardsInstructionAccounts>) -> Result<()> {
+        instructions::staker::claim_rewards(ctx)
+    }
+
+    pub fn lock_stake(ctx: Context<LockStakeInstructionAccounts>, amount: u64, lock_days: u16) -> Result<()> {
+        instructions::staker::lock_stake(ctx, amount, lock_days)
+    }
+
+    pub fn unlock_stake(ctx: Context<UnlockStakeInstructionAccounts>) -> Result<()> {
+        instructions::staker::unlock_stake(ctx)
+    }
+
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdrawInstructionAccounts>, amount: u64) -> Result<()> {
+        instructions::staker::emergency_withdraw(ctx, amount)
+    }
+
+    pub fn close_staker(ctx: Context<CloseStakerInstructionAccounts>, close_lp_ata: bool) -> Result<()> {
+        instructions::staker::close_staker(ctx, close_lp_ata)
+    }
+
+    pub fn set_staker_delegate(ctx: Context<SetStakerDelegateInstructionAccounts>, delegate: Pubkey) -> Result<()> {
+        instructions::staker::set_staker_delegate(ctx, delegate)
+    }
+
+    pub fn stake_position(ctx: Context<StakePositionInstructionAccounts>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::staker::stake_position(ctx, amount, proof)
+    }
+
+    pub fn claim_position(ctx: Context<ClaimPositionInstructionAccounts>, amount: Option<u64>) -> Result<()> {
+        instructions::staker::claim_position(ctx, amount)
+    }
+
+    pub fn claim_position_rewards(ctx: Context<ClaimPositionRewardsInstructionAccounts>) -> Result<()> {
+        instructions::staker::claim_position_rewards(ctx)
+    }
+
+    pub fn compound_position(ctx: Context<CompoundPositionInstructionAccounts>) -> Result<()> {
+        instructions::staker::compound_position(ctx)
+    }
+
+    pub fn unstake_position(ctx: Context<UnstakePositionInstructionAccounts>, amount: u64) -> Result<()> {
+        instructions::staker::unstake_position(ctx, amount)
+    }
+
+    pub fn close_position(ctx: Context<ClosePositionInstructionAccounts>) -> Result<()> {
+        instructions::staker::close_position(ctx)
     }
 
     // Trader instruction
-    pub fn swap(ctx: Context<SwapInstructionAccounts>, amount_in: u64, minimum_out: u64) -> Result<()> {
-        instructions::trader::swap(ctx, amount_in, minimum_out)
+    pub fn swap(ctx: Context<SwapInstructionAccounts>, amount_in: u64, minimum_out: u64, proof_in: Vec<[u8; 32]>, proof_out: Vec<[u8; 32]>) -> Result<()> {
+        instructions::trader::swap(ctx, amount_in, minimum_out, proof_in, proof_out)
     }
 }
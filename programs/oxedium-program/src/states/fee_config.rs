@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct FeeConfig {
+    pub recipients: Vec<FeeRecipient>,
+}
+
+/// Receiver of a share of the collected protocol yield
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FeeRecipient {
+    /// Wallet owning the token accounts that receive the share
+    pub owner: Pubkey,
+    /// Share of the protocol yield in basis points; all weights sum to 10_000
+    pub weight_bps: u64,
+}
@@ -1,7 +1,15 @@
 pub use treasury::*;
 pub use vault::*;
 pub use staker::*;
+pub use reward_stream::*;
+pub use queued_change::*;
+pub use multisig::*;
+pub use fee_config::*;
 
 pub mod treasury;
 pub mod vault;
-pub mod staker;
\ No newline at end of file
+pub mod staker;
+pub mod reward_stream;
+pub mod queued_change;
+pub mod multisig;
+pub mod fee_config;
\ No newline at end of file
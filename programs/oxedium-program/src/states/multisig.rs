@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Multisig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub signer_bump: u8,
+    pub owner_set_seqno: u32,
+}
+
+#[account]
+#[derive(Default)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub data: Vec<u8>,
+    pub accounts: Vec<ProposalAccount>,
+    pub approvals: Vec<bool>,
+    pub owner_set_seqno: u32,
+    pub executed: bool,
+}
+
+/// Account meta of the instruction executed by a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
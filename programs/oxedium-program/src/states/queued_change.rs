@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::instructions::admin::DepositCaps;
+
+#[account]
+pub struct QueuedChange {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub eta: i64,
+    pub change: ParameterChange,
+}
+
+/// A parameter change that goes through the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ParameterChange {
+    /// Protocol fee and deviation of the treasury
+    Treasury { fee_bps: u64, deviation: u64 },
+    /// Fee, oracle and staking parameters of the vault for `vault_mint`
+    Vault { vault_mint: Pubkey, params: VaultParams },
+    /// Delay (in seconds) applied to later queued changes
+    TimelockDelay { delay: i64 },
+}
+
+/// Vault parameters set by `update_vault` or a queued `ParameterChange::Vault`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct VaultParams {
+    pub base_fee: u64,
+    pub pyth_price_account: Pubkey,
+    pub max_age_price: u64,
+    pub min_stake_age: i64,
+    pub yield_drip_window: i64,
+    pub deposit_caps: DepositCaps,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct RewardStream {
+    pub vault: Pubkey,
+    pub reward_mint: Pubkey,
+
+    pub emission_per_second: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub last_update_time: i64,
+
+    pub cumulative_reward_per_lp: u128,
+    pub funded_amount: u64,
+    pub emitted_amount: u64
+}
@@ -1,9 +1,28 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default, InitSpace)]
 pub struct Staker {
     pub owner: Pubkey,
     pub vault: Pubkey,
     pub last_cumulative_yield: u128,
-    pub pending_claim: u64
+    pub pending_claim: u64,
+    pub last_cumulative_reward: u128,
+    pub pending_rewards: u64,
+
+    pub locked_amount: u64,
+    pub lock_end: i64,
+    pub boost_bps: u64,
+    pub last_cumulative_penalty: u128,
+
+    pub stake_time: i64,
+    pub unvested_claim: u64,
+
+    pub delegate: Pubkey,
+
+    pub version: u8,
+
+    pub last_settle_time: i64,
+
+    pub reserved: [u8; 24]
 }
\ No newline at end of file
@@ -1,9 +1,39 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default, InitSpace)]
 pub struct Treasury {
     pub stoptap: bool,
     pub admin: Pubkey,
     pub fee_bps: u64,
-    pub deviation: u64
-}
\ No newline at end of file
+    pub deviation: u64,
+    pub pending_admin: Pubkey,
+    pub pauser: Pubkey,
+    pub fee_manager: Pubkey,
+    pub vault_lister: Pubkey,
+    pub fee_collector: Pubkey,
+    pub guardian: Pubkey,
+    pub timelock_delay: i64,
+    pub max_base_fee: u64,
+    pub max_protocol_fee_bps: u64,
+    pub max_oracle_age: u64,
+    pub min_deviation: u64,
+
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+/// Operational roles delegated by the admin; the admin implicitly holds every role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Can set `stoptap` but not lift it
+    Pauser,
+    /// Can update treasury and vault fee/oracle parameters
+    FeeManager,
+    /// Can list new vaults and manage vault allowlists
+    VaultLister,
+    /// Can configure the protocol fee recipients
+    FeeCollector,
+    /// Can set and lift `stoptap`
+    Guardian,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default, InitSpace)]
 pub struct Vault {
     pub base_fee: u64,
     pub token_mint: Pubkey,
@@ -13,5 +14,47 @@ pub struct Vault {
     pub current_liquidity: u64,
     
     pub cumulative_yield_per_lp: u128,
-    pub protocol_yield: u64
+    pub protocol_yield: u64,
+
+    pub has_reward_stream: bool,
+
+    pub total_locked: u64,
+    pub total_boost_weight: u64,
+    pub cumulative_penalty_per_lp: u128,
+
+    pub min_stake_age: i64,
+    pub yield_drip_window: i64,
+    pub undripped_yield: u64,
+    pub last_drip_time: i64,
+
+    pub max_total_deposits: u64,
+    pub max_deposit_per_user: u64,
+    pub caps_in_usd: bool,
+
+    pub allowlist_enabled: bool,
+    pub allowlist_root: [u8; 32],
+
+    pub status: VaultStatus,
+
+    pub version: u8,
+
+    pub drip_end_time: i64,
+
+    pub unclaimed_yield: u64,
+
+    pub open_stakers: u64,
+
+    pub reserved: [u8; 8]
+}
+
+/// Lifecycle of a vault; it only moves forward, a retired vault ends up closed by `close_vault`
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VaultStatus {
+    /// Stakes and swaps in both directions
+    #[default]
+    Active,
+    /// Delisting: no new stakes or inbound swaps, the vault can still be swapped out of
+    SwapOnlyOut,
+    /// Only LP withdrawals, without the deficit penalty
+    WithdrawOnly,
 }
\ No newline at end of file
@@ -3,7 +3,40 @@ pub const MINT_SEED: &str = "mint-seed";
 
 pub const STAKER_SEED: &str = "staker-seed";
 
+pub const REWARD_STREAM_SEED: &str = "reward-stream-seed";
+pub const REWARD_ESCROW_SEED: &str = "reward-escrow-seed";
+
+pub const LOCK_SEED: &str = "lock-seed";
+
+pub const POSITION_SEED: &str = "position-seed";
+pub const POSITION_NFT_NAME: &str = "Oxedium LP Position";
+pub const POSITION_NFT_SYMBOL: &str = "OXLP";
+
 pub const OXEDIUM_SEED: &str = "oxedium-seed";
 pub const TREASURY_SEED: &str = "treasury-seed";
+pub const QUEUED_CHANGE_SEED: &str = "queued-change-seed";
+
+pub const MULTISIG_SEED: &str = "multisig-seed";
+pub const MULTISIG_SIGNER_SEED: &str = "multisig-signer-seed";
+pub const PROPOSAL_SEED: &str = "proposal-seed";
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+pub const FEE_CONFIG_SEED: &str = "fee-config-seed";
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+
+pub  const SCALE: u128 = 1_000_000_000_000;
+
+pub const USD_DECIMALS: u32 = 6; // decimals of USD-denominated deposit caps
+
+// Parameter bounds set by `init_treasury`, adjustable with `set_parameter_bounds`
+pub const DEFAULT_MAX_BASE_FEE: u64 = 500; // 5%
+pub const DEFAULT_MAX_PROTOCOL_FEE_BPS: u64 = 500; // 5%
+pub const DEFAULT_MAX_ORACLE_AGE: u64 = 300; // seconds
+pub const DEFAULT_MIN_DEVIATION: u64 = 2; // swaps above 50% of a vault always pay the penalty
+
+// Current layout versions; accounts without a version field are v0
+pub const TREASURY_VERSION: u8 = 1;
+pub const VAULT_VERSION: u8 = 1;
+pub const STAKER_VERSION: u8 = 1;
 
-pub  const SCALE: u128 = 1_000_000_000_000;
\ No newline at end of file
+pub const EARLY_UNLOCK_PENALTY_BPS: u64 = 1_000; // 10% of the locked LP
\ No newline at end of file
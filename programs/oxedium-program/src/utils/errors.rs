@@ -44,4 +44,133 @@ pub enum OxediumError {
 
     #[msg("Deviation must be greater than zero")]
     InvalidDeviation,
-}
\ No newline at end of file
+
+    #[msg("Reward stream account required")]
+    MissingRewardStream,
+
+    #[msg("Invalid reward stream schedule")]
+    InvalidRewardSchedule,
+
+    #[msg("Lock duration must be 30, 90 or 180 days")]
+    InvalidLockDuration,
+
+    #[msg("Stake is already locked")]
+    LockActive,
+
+    #[msg("No locked stake")]
+    NoActiveLock,
+
+    #[msg("Duration must not be negative")]
+    InvalidDuration,
+
+    #[msg("Vault deposit cap exceeded")]
+    VaultDepositCapExceeded,
+
+    #[msg("User deposit cap exceeded")]
+    UserDepositCapExceeded,
+
+    #[msg("Price account required for USD deposit caps")]
+    MissingPriceAccount,
+
+    #[msg("Wallet is not allowlisted for this vault")]
+    NotAllowlisted,
+
+    #[msg("Emergency withdrawals are only available while stoptap is active")]
+    NotPaused,
+
+    #[msg("Staker still holds LP or unclaimed rewards")]
+    StakerNotEmpty,
+
+    #[msg("Signer is neither the staker owner nor its delegate")]
+    InvalidStakerAuthority,
+
+    #[msg("Delegates can only claim to the owner's token accounts")]
+    InvalidRecipient,
+
+    #[msg("Claim amount exceeds pending yield")]
+    InsufficientClaim,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Signer does not hold the position NFT")]
+    NotPositionHolder,
+
+    #[msg("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+
+    #[msg("Parameter changes must be queued while a timelock delay is set")]
+    TimelockActive,
+
+    #[msg("Queued change is not executable yet")]
+    TimelockNotElapsed,
+
+    #[msg("Accounts do not match the queued change")]
+    InvalidQueuedChange,
+
+    #[msg("Invalid multisig signers or threshold")]
+    InvalidMultisigConfig,
+
+    #[msg("Signer is not a multisig signer")]
+    NotMultisigSigner,
+
+    #[msg("Instruction is not allowed for multisig proposals")]
+    ActionNotWhitelisted,
+
+    #[msg("Proposal was created for a previous multisig signer set")]
+    StaleProposal,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal has not reached the approval threshold")]
+    NotEnoughApprovals,
+
+    #[msg("Fee recipients must be 1 to 5 wallets with weights summing to 10000 bps")]
+    InvalidFeeRecipients,
+
+    #[msg("Base fee exceeds the configured maximum")]
+    BaseFeeTooHigh,
+
+    #[msg("Protocol fee exceeds the configured maximum")]
+    ProtocolFeeTooHigh,
+
+    #[msg("Max price age exceeds the configured maximum oracle age")]
+    OracleAgeTooHigh,
+
+    #[msg("Deviation is below the configured minimum")]
+    DeviationTooLow,
+
+    #[msg("Invalid parameter bounds")]
+    InvalidParameterBounds,
+
+    #[msg("Vault no longer accepts stakes or inbound swaps")]
+    VaultNotActive,
+
+    #[msg("Vault only allows withdrawals")]
+    VaultWithdrawOnly,
+
+    #[msg("Vault status can only move forward")]
+    InvalidVaultStatus,
+
+    #[msg("Vault still holds LP supply or uncollected protocol yield")]
+    VaultNotEmpty,
+
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account data does not match a known layout")]
+    InvalidAccountLayout,
+
+    #[msg("Positions are not available on vaults with a per-user deposit cap")]
+    PositionsNotAllowed,
+
+    #[msg("Staker account does not belong to the vault")]
+    InvalidStakerAccount,
+
+    #[msg("Source and destination vaults must differ")]
+    SameVault,
+}
@@ -2,8 +2,13 @@
 mod components {
 
     use anchor_lang::prelude::Pubkey;
-    use oxedium_program::{components::{calculate_fee_amount, calculate_staker_yield, conf_fee_bps, fees_setting, raw_amount_out}, states::Vault, utils::SCALE};
+    use anchor_lang::{AccountSerialize, AnchorSerialize, Discriminator};
+    use oxedium_program::events::Paused;
+    use oxedium_program::instruction;
+    use oxedium_program::instructions::admin::{DepositCaps, ParameterBounds};
+    use oxedium_program::{components::{accrue_vault_yield, allowlist_leaf, apply_swap_result, apply_vault_params, boost_weight, check_allowlist, calculate_fee_amount, claim_group_addresses, claim_group_count, check_deposit_caps, check_distinct_vaults, check_parameter_bounds, check_positions_allowed, check_treasury_params, check_vault_bounds, check_vault_closable, check_fee_recipients, check_multisig_config, count_approvals, check_pending_admin, check_role, check_staker_authority, check_timelock_disabled, check_vault_inflow, check_vault_outflow, check_vault_params, deposit_value, lock_boost_bps, calculate_staker_yield, conf_fee_bps, drip_vault_yield, emergency_withdraw_amount, unexpired_boost_yield, fees_setting, forfeit_unvested_yield, is_whitelisted_action, migrate_fee_bps, multisig_signer_index, raw_amount_out, register_staker, set_role_holder, settle_staker_rewards, settle_staker_yield, split_protocol_yield, unstake_fee_bps, update_reward_stream, upgrade_staker, upgrade_treasury, upgrade_vault, verify_merkle_proof, SwapMathResult}, states::{FeeRecipient, Multisig, ParameterChange, RewardStream, Role, Staker, Treasury, Vault, VaultParams, VaultStatus}, utils::{DEFAULT_MAX_BASE_FEE, DEFAULT_MIN_DEVIATION, SCALE, STAKER_SEED, STAKER_VERSION, TREASURY_VERSION, VAULT_SEED, VAULT_VERSION}};
     use pyth_solana_receiver_sdk::price_update::PriceFeedMessage;
+    use solana_sha256_hasher::hashv;
     
 
 #[test]
@@ -47,6 +52,23 @@ fn calculating_yield() {
     println!("Yield: {}", yield_amount);
 }
 
+#[test]
+fn settling_staker_yield() {
+    let pubkey = Pubkey::default();
+    let mut vault = Vault {base_fee: 1, initial_liquidity: 1000000000, current_liquidity: 1000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 2 * SCALE, protocol_yield: 0, ..Default::default()};
+    let mut staker = Staker {owner: pubkey, vault: pubkey, last_cumulative_yield: SCALE, pending_claim: 5, ..Default::default()};
+
+    settle_staker_yield(&mut vault, &mut staker, 100, 0);
+
+    // 100 LP * (2 - 1) yield per LP on top of the already pending 5
+    assert_eq!(staker.pending_claim, 105);
+    assert_eq!(staker.last_cumulative_yield, 2 * SCALE);
+
+    // Settling again at the same checkpoint adds nothing
+    settle_staker_yield(&mut vault, &mut staker, 100, 0);
+    assert_eq!(staker.pending_claim, 105);
+}
+
 
 #[test]
 fn testing_raw_amount_out() {
@@ -65,8 +87,8 @@ fn testing_raw_amount_out() {
 #[test]
 fn testing_fees_setting() {
     let pubkey = Pubkey::default();
-    let vault_in = Vault {base_fee: 1, initial_liquidity: 1000000000000, current_liquidity: 900000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0};
-    let vault_out = Vault {base_fee: 1, initial_liquidity: 150000000000, current_liquidity: 100000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0};
+    let vault_in = Vault {base_fee: 1, initial_liquidity: 1000000000000, current_liquidity: 900000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+    let vault_out = Vault {base_fee: 1, initial_liquidity: 150000000000, current_liquidity: 100000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
 
     let fee = fees_setting(&vault_in, &vault_out);
 
@@ -74,6 +96,500 @@ fn testing_fees_setting() {
     //assert_eq!(amount_out, 24604301);
 }
 
+#[test]
+fn migrate_fee_discounted_into_deficit_vault() {
+    // Source vault holds surplus, destination is drained → half base fee
+    let pubkey = Pubkey::default();
+    let vault_from = Vault {base_fee: 30, initial_liquidity: 1000000000000, current_liquidity: 1200000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+    let vault_to = Vault {base_fee: 30, initial_liquidity: 1000000000000, current_liquidity: 500000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+
+    assert_eq!(migrate_fee_bps(&vault_from, &vault_to), 15);
+}
+
+#[test]
+fn migrate_fee_grows_out_of_deficit_vault() {
+    // Source vault is drained, destination holds surplus → curve on the source deficit
+    let pubkey = Pubkey::default();
+    let vault_from = Vault {base_fee: 30, initial_liquidity: 1000000000000, current_liquidity: 500000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+    let vault_to = Vault {base_fee: 30, initial_liquidity: 1000000000000, current_liquidity: 1200000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+
+    // 50% deficit → 25% of the way from the base fee to 100%
+    assert_eq!(migrate_fee_bps(&vault_from, &vault_to), 30 + (10_000 - 30) / 4);
+
+    // Neither vault in deficit → plain base fee
+    let balanced = Vault { current_liquidity: 1000000000000, ..vault_from.clone() };
+    assert_eq!(migrate_fee_bps(&balanced, &vault_to), 30);
+}
+
+// ─────────────────────────────────────────────
+// time-locked boost unit tests
+// ─────────────────────────────────────────────
+
+#[test]
+fn lock_boost_tiers() {
+    assert_eq!(lock_boost_bps(30).unwrap(), 11_000);
+    assert_eq!(lock_boost_bps(90).unwrap(), 12_500);
+    assert_eq!(lock_boost_bps(180).unwrap(), 15_000);
+    assert!(lock_boost_bps(7).is_err(), "unsupported lock duration must be rejected");
+}
+
+#[test]
+fn boosted_lock_earns_larger_share() {
+    // 1_000 liquid LP + 1_000 LP locked at 1.5x → total weight 2_500
+    let mut vault = Vault {initial_liquidity: 2_000, total_locked: 1_000, total_boost_weight: boost_weight(1_000, 15_000), ..Default::default()};
+    assert_eq!(vault.total_boost_weight, 500);
+
+    accrue_vault_yield(&mut vault, 2_500, 0);
+
+    let mut liquid = Staker::default();
+    let mut locked = Staker {locked_amount: 1_000, boost_bps: 15_000, lock_end: 100, ..Default::default()};
+
+    settle_staker_yield(&mut vault, &mut liquid, 1_000, 0);
+    settle_staker_yield(&mut vault, &mut locked, 0, 0);
+
+    assert_eq!(liquid.pending_claim, 1_000);
+    assert_eq!(locked.pending_claim, 1_500);
+}
+
+#[test]
+fn early_unlock_penalty_only_paid_to_liquid_lp() {
+    let mut vault = Vault {cumulative_penalty_per_lp: SCALE, ..Default::default()};
+
+    let mut liquid = Staker::default();
+    let mut locked = Staker {locked_amount: 1_000, boost_bps: 11_000, lock_end: 100, ..Default::default()};
+
+    settle_staker_yield(&mut vault, &mut liquid, 1_000, 0);
+    settle_staker_yield(&mut vault, &mut locked, 0, 0);
+
+    assert_eq!(liquid.pending_claim, 1_000);
+    assert_eq!(locked.pending_claim, 0);
+}
+
+#[test]
+fn boost_stops_at_lock_end() {
+    // 1_000 liquid LP + 1_000 LP locked at 1.5x until t = 40 → total weight 2_500
+    let mut vault = Vault {initial_liquidity: 2_000, total_locked: 1_000, total_boost_weight: boost_weight(1_000, 15_000), ..Default::default()};
+    let mut liquid = Staker::default();
+    let mut locked = Staker {locked_amount: 1_000, boost_bps: 15_000, lock_end: 40, ..Default::default()};
+
+    accrue_vault_yield(&mut vault, 2_500, 0);
+
+    // Settled at t = 100: 1_000 base + 40% of the 500 boost yield
+    settle_staker_yield(&mut vault, &mut locked, 0, 100);
+    assert_eq!(locked.unvested_claim + locked.pending_claim, 1_200);
+    assert_eq!((vault.total_boost_weight, locked.boost_bps), (0, 10_000));
+
+    // The remaining 300 is shared by the 2_000 unboosted LP
+    settle_staker_yield(&mut vault, &mut liquid, 1_000, 100);
+    assert_eq!(liquid.pending_claim, 1_150);
+    settle_staker_yield(&mut vault, &mut locked, 0, 100);
+    assert_eq!(locked.pending_claim, 1_350);
+
+    // 100 boost yield over 10 seconds, lock expired after 4 of them
+    assert_eq!(unexpired_boost_yield(100, 0, 4, 10), 40);
+    assert_eq!(unexpired_boost_yield(100, 20, 4, 30), 0);
+
+    // Later yield is earned without the boost
+    accrue_vault_yield(&mut vault, 2_000, 200);
+    settle_staker_yield(&mut vault, &mut locked, 0, 200);
+    assert_eq!(locked.pending_claim, 2_350);
+}
+
+#[test]
+fn drip_window_releases_fees_linearly() {
+    let mut vault = Vault {initial_liquidity: 1_000, yield_drip_window: 100, ..Default::default()};
+
+    // Fee is buffered instead of being credited immediately
+    accrue_vault_yield(&mut vault, 1_000, 0);
+    assert_eq!(vault.undripped_yield, 1_000);
+    assert_eq!(vault.cumulative_yield_per_lp, 0);
+
+    // Half of the window releases half of the fee
+    drip_vault_yield(&mut vault, 50);
+    assert_eq!(vault.undripped_yield, 500);
+    assert_eq!(vault.cumulative_yield_per_lp, SCALE / 2);
+
+    // The rate stays constant: another quarter of the window releases another quarter
+    drip_vault_yield(&mut vault, 75);
+    assert_eq!(vault.undripped_yield, 250);
+
+    // The rest is released once the window has passed
+    drip_vault_yield(&mut vault, 500);
+    assert_eq!(vault.undripped_yield, 0);
+    assert_eq!(vault.cumulative_yield_per_lp, SCALE);
+}
+
+#[test]
+fn frequent_drips_do_not_strand_the_buffer() {
+    let mut vault = Vault {initial_liquidity: 1_000, yield_drip_window: 100, ..Default::default()};
+    accrue_vault_yield(&mut vault, 3, 0);
+
+    // Every per-second release rounds to zero, but the elapsed time is not lost
+    for now in 1..50 {
+        drip_vault_yield(&mut vault, now);
+    }
+    assert_eq!(vault.undripped_yield, 2);
+
+    drip_vault_yield(&mut vault, 100);
+    assert_eq!(vault.undripped_yield, 0);
+
+    // A new fee restarts the window for the whole buffer
+    accrue_vault_yield(&mut vault, 100, 200);
+    drip_vault_yield(&mut vault, 250);
+    assert_eq!(vault.undripped_yield, 50);
+    assert_eq!(vault.drip_end_time, 300);
+}
+
+#[test]
+fn yield_vests_after_min_stake_age() {
+    let mut vault = Vault {initial_liquidity: 1_000, min_stake_age: 100, cumulative_yield_per_lp: SCALE, ..Default::default()};
+    let mut staker = Staker {stake_time: 0, ..Default::default()};
+
+    // Too young: yield is held back
+    settle_staker_yield(&mut vault, &mut staker, 100, 50);
+    assert_eq!(staker.unvested_claim, 100);
+    assert_eq!(staker.pending_claim, 0);
+
+    // Aged: held-back yield becomes claimable together with new yield
+    vault.cumulative_yield_per_lp = 2 * SCALE;
+    settle_staker_yield(&mut vault, &mut staker, 100, 100);
+    assert_eq!(staker.unvested_claim, 0);
+    assert_eq!(staker.pending_claim, 200);
+}
+
+#[test]
+fn early_exit_forfeits_unvested_yield() {
+    let mut vault = Vault {initial_liquidity: 1_000, min_stake_age: 100, cumulative_yield_per_lp: SCALE, ..Default::default()};
+    let mut staker = Staker::default();
+
+    settle_staker_yield(&mut vault, &mut staker, 100, 10);
+    assert_eq!(forfeit_unvested_yield(&mut vault, &mut staker, 100, 100, 10), 100);
+    assert_eq!(staker.unvested_claim, 0);
+
+    // Forfeited yield is redistributed to the vault's LP
+    assert_eq!(vault.cumulative_yield_per_lp, SCALE + SCALE / 10);
+}
+
+#[test]
+fn partial_exit_forfeits_unvested_yield_pro_rata() {
+    let mut vault = Vault {initial_liquidity: 1_000, min_stake_age: 100, cumulative_yield_per_lp: SCALE, ..Default::default()};
+    let mut staker = Staker::default();
+
+    settle_staker_yield(&mut vault, &mut staker, 100, 10);
+
+    // Withdrawing a quarter of the position forfeits a quarter of the unvested yield
+    assert_eq!(forfeit_unvested_yield(&mut vault, &mut staker, 25, 100, 10), 25);
+    assert_eq!(staker.unvested_claim, 75);
+}
+
+#[test]
+fn migrate_rejects_same_vault() {
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let vault_a = Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), mint_a.as_ref()], &oxedium_program::ID).0;
+    let vault_b = Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), mint_b.as_ref()], &oxedium_program::ID).0;
+
+    assert!(check_distinct_vaults(&vault_a, &vault_b).is_ok());
+    assert!(check_distinct_vaults(&vault_a, &vault_a).is_err(), "migrating into the same vault");
+}
+
+#[test]
+fn deposit_caps_in_token_units() {
+    let vault = Vault {initial_liquidity: 900, max_total_deposits: 1_000, max_deposit_per_user: 300, ..Default::default()};
+
+    assert!(check_deposit_caps(&vault, 200, 100, 6, None).is_ok());
+    assert!(check_deposit_caps(&vault, 0, 101, 6, None).is_err(), "vault cap must be enforced");
+    assert!(check_deposit_caps(&vault, 250, 60, 6, None).is_err(), "user cap must be enforced");
+
+    // Zero caps mean no limit
+    let uncapped = Vault {initial_liquidity: u64::MAX / 2, ..Default::default()};
+    assert!(check_deposit_caps(&uncapped, 0, 1_000_000, 6, None).is_ok());
+}
+
+#[test]
+fn deposit_caps_in_usd() {
+    // SOL at $100 (exponent -8), caps of $1_000 and $500 with 6 decimals
+    let sol = PriceFeedMessage { feed_id: [0; 32], price: 10_000_000_000, conf: 0, exponent: -8, publish_time: 1, prev_publish_time: 1, ema_price: 1, ema_conf: 1 };
+    assert_eq!(deposit_value(1_000_000_000, 9, Some(&sol)).unwrap(), 100_000_000);
+    assert_eq!(deposit_value(1_000_000_000, 9, None).unwrap(), 1_000_000_000);
+
+    // The same $100 price with a positive exponent (1 * 10^2) multiplies instead
+    let positive = PriceFeedMessage { price: 1, exponent: 2, ..sol };
+    assert_eq!(deposit_value(1_000_000_000, 9, Some(&positive)).unwrap(), 100_000_000);
+
+    // Zero exponent: $3 per token
+    let flat = PriceFeedMessage { price: 3, exponent: 0, ..sol };
+    assert_eq!(deposit_value(2_000_000_000, 9, Some(&flat)).unwrap(), 6_000_000);
+
+    let vault = Vault {initial_liquidity: 5_000_000_000, max_total_deposits: 1_000_000_000, max_deposit_per_user: 500_000_000, caps_in_usd: true, ..Default::default()};
+
+    assert!(check_deposit_caps(&vault, 0, 5_000_000_000, 9, Some(&sol)).is_ok());
+    assert!(check_deposit_caps(&vault, 0, 5_010_000_000, 9, Some(&sol)).is_err(), "user cap of $500 must be enforced");
+    assert!(check_deposit_caps(&vault, 0, 1_000_000_000, 9, None).is_err(), "USD caps require a price");
+}
+
+#[test]
+fn claim_as_books_the_yield_on_both_vaults() {
+    let mut vault_in = Vault {initial_liquidity: 1_000, current_liquidity: 1_000, unclaimed_yield: 50, ..Default::default()};
+    let mut vault_out = Vault {initial_liquidity: 1_000, current_liquidity: 1_000, ..Default::default()};
+    let result = SwapMathResult {swap_fee_bps: 0, raw_amount_out: 100, net_amount_out: 90, lp_fee_amount: 8, protocol_fee_amount: 2};
+
+    // The claimed yield stays in the treasury and becomes the input vault's liquidity
+    vault_in.unclaimed_yield -= 50;
+    apply_swap_result(&mut vault_in, &mut vault_out, 50, &result, 0);
+
+    assert_eq!(vault_in.current_liquidity, 1_050);
+    assert_eq!(vault_in.initial_liquidity, 1_000);
+    assert_eq!(vault_out.current_liquidity, 910);
+    assert_eq!(vault_out.protocol_yield, 2);
+    assert_eq!(vault_out.unclaimed_yield, 8);
+}
+
+#[test]
+fn emergency_withdraw_is_pro_rata_of_remaining_balance() {
+    // 700 tokens held, of which 100 uncollected protocol yield, 50 buffered fees and
+    // 50 unclaimed staker yield → 500 left for 1_000 LP
+    let vault = Vault {initial_liquidity: 1_000, current_liquidity: 900, protocol_yield: 100, undripped_yield: 50, unclaimed_yield: 50, ..Default::default()};
+
+    assert_eq!(emergency_withdraw_amount(&vault, 700, 500).unwrap(), 250);
+    assert_eq!(emergency_withdraw_amount(&vault, 700, 1_000).unwrap(), 500);
+
+    let empty = Vault::default();
+    assert_eq!(emergency_withdraw_amount(&empty, 0, 0).unwrap(), 0);
+}
+
+#[test]
+fn claims_and_credits_track_unclaimed_yield() {
+    let mut vault = Vault {initial_liquidity: 1_000, min_stake_age: 100, ..Default::default()};
+    let mut staker = Staker::default();
+
+    accrue_vault_yield(&mut vault, 300, 0);
+    assert_eq!(vault.unclaimed_yield, 300);
+
+    // Forfeiting re-credits yield that was already counted
+    settle_staker_yield(&mut vault, &mut staker, 500, 10);
+    assert_eq!(staker.unvested_claim, 150);
+    forfeit_unvested_yield(&mut vault, &mut staker, 500, 500, 10);
+    assert_eq!(vault.unclaimed_yield, 300);
+}
+
+#[test]
+fn positions_not_allowed_with_per_user_cap() {
+    assert!(check_positions_allowed(&Vault::default()).is_ok());
+    assert!(check_positions_allowed(&Vault {max_total_deposits: 1_000, ..Default::default()}).is_ok());
+    assert!(check_positions_allowed(&Vault {max_deposit_per_user: 1_000, ..Default::default()}).is_err());
+}
+
+#[test]
+fn position_lifecycle_settles_against_escrow() {
+    let mut vault = Vault {initial_liquidity: 1_000, current_liquidity: 1_000, max_total_deposits: 1_300, min_stake_age: 100, ..Default::default()};
+    let mut position = Staker::default();
+    let mut escrow: u64 = 500;
+
+    // Half of the vault's LP sits in the escrow and earns half of the fees
+    accrue_vault_yield(&mut vault, 300, 0);
+    settle_staker_yield(&mut vault, &mut position, escrow, 100);
+    assert_eq!(position.pending_claim, 150);
+
+    // Compounding counts against the vault cap, then moves the claim into escrowed LP
+    assert!(check_deposit_caps(&vault, escrow, 301, 6, None).is_err());
+    assert!(check_deposit_caps(&vault, escrow, position.pending_claim, 6, None).is_ok());
+    vault.unclaimed_yield = vault.unclaimed_yield.saturating_sub(position.pending_claim);
+    vault.initial_liquidity += position.pending_claim;
+    vault.current_liquidity += position.pending_claim;
+    escrow += position.pending_claim;
+    position.pending_claim = 0;
+    assert_eq!((escrow, vault.unclaimed_yield), (650, 150));
+
+    // The restaked yield already sits in the treasury: compounding adds no deficit
+    assert_eq!(vault.current_liquidity, vault.initial_liquidity);
+
+    // A partial unstake of unvested yield forfeits its share
+    position.stake_time = 100;
+    accrue_vault_yield(&mut vault, 1_150, 100);
+    settle_staker_yield(&mut vault, &mut position, escrow, 150);
+    assert_eq!(position.unvested_claim, 650);
+    assert_eq!(forfeit_unvested_yield(&mut vault, &mut position, 325, escrow, 150), 325);
+    escrow -= 325;
+
+    // Closing needs an empty escrow and forfeits whatever has not vested
+    escrow -= 325;
+    settle_staker_yield(&mut vault, &mut position, escrow, 150);
+    assert_eq!(forfeit_unvested_yield(&mut vault, &mut position, 0, 0, 150), 325);
+    assert_eq!((escrow, position.pending_claim, position.unvested_claim), (0, 0, 0));
+}
+
+#[test]
+fn position_rewards_are_claimed_before_closing() {
+    let mut vault = Vault {has_reward_stream: true, status: VaultStatus::WithdrawOnly, ..Default::default()};
+    let mut stream = make_stream();
+    let mut position = Staker::default();
+
+    // Stake a position as the only LP once the stream is funded
+    register_staker(&mut vault, &mut position, Pubkey::new_unique(), Pubkey::new_unique());
+    settle_staker_rewards(&vault, Some(&mut stream), &mut position, 0, 1_000).unwrap();
+    let mut escrow: u64 = 500;
+    vault.initial_liquidity += escrow;
+
+    // The holder claims what the position earned
+    settle_staker_rewards(&vault, Some(&mut stream), &mut position, escrow, 1_100).unwrap();
+    assert_eq!(position.pending_rewards, 1_000);
+    position.pending_rewards = 0;
+
+    // Unstaking settles the rest, which must be claimed before closing
+    settle_staker_rewards(&vault, Some(&mut stream), &mut position, escrow, 1_200).unwrap();
+    vault.initial_liquidity -= escrow;
+    escrow = 0;
+    assert_eq!(position.pending_rewards, 1_000);
+    position.pending_rewards = 0;
+
+    // Closing finds nothing left, so the vault can be retired
+    settle_staker_rewards(&vault, Some(&mut stream), &mut position, escrow, 1_300).unwrap();
+    assert_eq!(position.pending_rewards, 0);
+    vault.open_stakers -= 1;
+    assert!(check_vault_closable(&vault, 0).is_ok());
+}
+
+#[test]
+fn staker_authority_accepts_owner_and_delegate() {
+    let owner = Pubkey::new_from_array([1; 32]);
+    let delegate = Pubkey::new_from_array([2; 32]);
+    let stranger = Pubkey::new_from_array([3; 32]);
+
+    let mut staker = Staker {owner, ..Default::default()};
+    assert!(check_staker_authority(&staker, &owner).is_ok());
+    assert!(check_staker_authority(&staker, &delegate).is_err());
+    // An unset delegate must not authorize the default pubkey
+    assert!(check_staker_authority(&staker, &Pubkey::default()).is_err());
+
+    staker.delegate = delegate;
+    assert!(check_staker_authority(&staker, &delegate).is_ok());
+    assert!(check_staker_authority(&staker, &stranger).is_err());
+}
+
+#[test]
+fn unstake_fee_only_for_drained_vaults() {
+    let healthy = Vault {initial_liquidity: 1_000, current_liquidity: 500, ..Default::default()};
+    let drained = Vault {initial_liquidity: 1_000, current_liquidity: 499, ..Default::default()};
+
+    assert_eq!(unstake_fee_bps(&healthy), 0);
+    assert_eq!(unstake_fee_bps(&drained), 200);
+}
+
+// ─────────────────────────────────────────────
+// Merkle allowlist unit tests
+// ─────────────────────────────────────────────
+
+fn merkle_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[0x01], &low, &high]).to_bytes()
+}
+
+/// Allowlist of four wallets: returns the wallets, their leaves and the root
+fn make_allowlist() -> (Vec<Pubkey>, Vec<[u8; 32]>, [u8; 32]) {
+    let wallets: Vec<Pubkey> = (1..=4).map(|i| Pubkey::new_from_array([i; 32])).collect();
+    let leaves: Vec<[u8; 32]> = wallets.iter().map(allowlist_leaf).collect();
+    let root = merkle_node(merkle_node(leaves[0], leaves[1]), merkle_node(leaves[2], leaves[3]));
+    (wallets, leaves, root)
+}
+
+#[test]
+fn merkle_proof_accepts_every_member() {
+    let (_, leaves, root) = make_allowlist();
+    let left = merkle_node(leaves[0], leaves[1]);
+    let right = merkle_node(leaves[2], leaves[3]);
+
+    assert!(verify_merkle_proof(&[leaves[1], right], root, leaves[0]));
+    assert!(verify_merkle_proof(&[leaves[0], right], root, leaves[1]));
+    assert!(verify_merkle_proof(&[leaves[3], left], root, leaves[2]));
+    assert!(verify_merkle_proof(&[leaves[2], left], root, leaves[3]));
+}
+
+#[test]
+fn merkle_proof_rejects_invalid_proofs() {
+    let (_, leaves, root) = make_allowlist();
+    let right = merkle_node(leaves[2], leaves[3]);
+    let outsider = allowlist_leaf(&Pubkey::new_from_array([9; 32]));
+
+    assert!(!verify_merkle_proof(&[leaves[1], right], root, outsider), "non-member must be rejected");
+    assert!(!verify_merkle_proof(&[leaves[1]], root, leaves[0]), "truncated proof must be rejected");
+    assert!(!verify_merkle_proof(&[], root, leaves[0]), "empty proof must be rejected");
+}
+
+#[test]
+fn allowlist_only_enforced_when_enabled() {
+    let (wallets, leaves, root) = make_allowlist();
+    let proof = [leaves[1], merkle_node(leaves[2], leaves[3])];
+    let outsider = Pubkey::new_from_array([9; 32]);
+
+    let open = Vault {allowlist_root: root, ..Default::default()};
+    assert!(check_allowlist(&open, &outsider, &[]).is_ok());
+
+    let permissioned = Vault {allowlist_enabled: true, allowlist_root: root, ..Default::default()};
+    assert!(check_allowlist(&permissioned, &wallets[0], &proof).is_ok());
+    assert!(check_allowlist(&permissioned, &outsider, &proof).is_err());
+    assert!(check_allowlist(&permissioned, &wallets[0], &[]).is_err());
+}
+
+// ─────────────────────────────────────────────
+// update_reward_stream unit tests
+// ─────────────────────────────────────────────
+
+fn make_stream() -> RewardStream {
+    RewardStream {
+        emission_per_second: 10,
+        start_time: 1_000,
+        end_time: 2_000,
+        last_update_time: 1_000,
+        funded_amount: 10_000,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reward_stream_emits_per_second() {
+    // 100 seconds at 10 tokens/s spread over 500 LP → 2 tokens per LP
+    let mut stream = make_stream();
+    update_reward_stream(&mut stream, 500, 1_100).unwrap();
+
+    assert_eq!(stream.emitted_amount, 1_000);
+    assert_eq!(stream.cumulative_reward_per_lp, 2 * SCALE);
+    assert_eq!(stream.last_update_time, 1_100);
+}
+
+#[test]
+fn reward_stream_does_not_emit_outside_schedule() {
+    // Before start nothing accrues
+    let mut stream = make_stream();
+    update_reward_stream(&mut stream, 500, 900).unwrap();
+    assert_eq!(stream.emitted_amount, 0);
+
+    // After end emission stops at end_time
+    update_reward_stream(&mut stream, 500, 5_000).unwrap();
+    assert_eq!(stream.emitted_amount, 10_000);
+    assert_eq!(stream.last_update_time, 2_000);
+}
+
+#[test]
+fn reward_stream_caps_emission_at_funding() {
+    // 1000 seconds at 10 tokens/s would be 10_000, but only 4_000 are funded
+    let mut stream = RewardStream { funded_amount: 4_000, ..make_stream() };
+    update_reward_stream(&mut stream, 1_000, 2_000).unwrap();
+
+    assert_eq!(stream.emitted_amount, 4_000);
+    assert_eq!(stream.cumulative_reward_per_lp, 4 * SCALE);
+}
+
+#[test]
+fn reward_stream_skips_emission_without_lp() {
+    // No LP supply → nothing emitted, the checkpoint still advances
+    let mut stream = make_stream();
+    update_reward_stream(&mut stream, 0, 1_500).unwrap();
+
+    assert_eq!(stream.emitted_amount, 0);
+    assert_eq!(stream.cumulative_reward_per_lp, 0);
+    assert_eq!(stream.last_update_time, 1_500);
+}
+
 // ─────────────────────────────────────────────
 // conf_fee_bps unit tests
 // ─────────────────────────────────────────────
@@ -137,4 +653,363 @@ fn conf_fee_asymmetric_oracles() {
     assert_eq!(fee, 70, "sum of independent conf fees should be 70 bps");
 }
 
-}
\ No newline at end of file
+#[test]
+fn pending_admin_must_match_signer() {
+    let admin = Pubkey::new_unique();
+    let pending = Pubkey::new_unique();
+    let mut treasury = Treasury { admin, ..Default::default() };
+
+    // No handover in progress: nobody can accept, not even the default key
+    assert!(check_pending_admin(&treasury, &pending).is_err());
+    assert!(check_pending_admin(&treasury, &Pubkey::default()).is_err());
+
+    treasury.pending_admin = pending;
+    assert!(check_pending_admin(&treasury, &pending).is_ok());
+    assert!(check_pending_admin(&treasury, &admin).is_err());
+}
+
+#[test]
+fn stoptap_toggles_are_indexed_as_paused() {
+    // Indexers match the event by the discriminator of its name
+    assert_eq!(Paused::DISCRIMINATOR, &hashv(&[b"event:Paused"]).to_bytes()[..8]);
+}
+
+#[test]
+fn role_holders_and_admin_pass_role_checks() {
+    let admin = Pubkey::new_unique();
+    let bot = Pubkey::new_unique();
+    let mut treasury = Treasury { admin, ..Default::default() };
+
+    // Unassigned roles fall back to the admin only
+    assert!(check_role(&treasury, Role::Pauser, &admin).is_ok());
+    assert!(check_role(&treasury, Role::Pauser, &bot).is_err());
+    assert!(check_role(&treasury, Role::Pauser, &Pubkey::default()).is_err());
+
+    assert_eq!(set_role_holder(&mut treasury, Role::Pauser, bot), Pubkey::default());
+    assert!(check_role(&treasury, Role::Pauser, &bot).is_ok());
+    assert!(check_role(&treasury, Role::FeeCollector, &bot).is_err(), "pauser must not collect fees");
+    assert!(check_role(&treasury, Role::Guardian, &bot).is_err());
+    assert!(check_role(&treasury, Role::Pauser, &admin).is_ok());
+
+    assert_eq!(set_role_holder(&mut treasury, Role::Pauser, Pubkey::default()), bot);
+    assert!(check_role(&treasury, Role::Pauser, &bot).is_err());
+}
+
+#[test]
+fn timelock_blocks_instant_updates() {
+    let mut treasury = Treasury::default();
+    assert!(check_timelock_disabled(&treasury).is_ok());
+
+    treasury.timelock_delay = 86_400;
+    assert!(check_timelock_disabled(&treasury).is_err());
+}
+
+#[test]
+fn vault_params_reject_negative_durations() {
+    let treasury = Treasury { max_base_fee: 500, max_oracle_age: 300, ..Default::default() };
+    let params = VaultParams { min_stake_age: 60, yield_drip_window: 3_600, ..Default::default() };
+    assert!(check_vault_params(&treasury, &params).is_ok());
+
+    assert!(check_vault_params(&treasury, &VaultParams { min_stake_age: -1, ..params }).is_err());
+    assert!(check_vault_params(&treasury, &VaultParams { yield_drip_window: -1, ..params }).is_err());
+}
+
+#[test]
+fn queued_vault_change_applies_all_params() {
+    let oracle = Pubkey::new_unique();
+    let mut vault = Vault { base_fee: 1, max_age_price: 30, ..Default::default() };
+    let params = VaultParams {
+        base_fee: 25,
+        pyth_price_account: oracle,
+        max_age_price: 60,
+        min_stake_age: 600,
+        yield_drip_window: 3_600,
+        deposit_caps: DepositCaps { max_total_deposits: 1_000, max_deposit_per_user: 100, caps_in_usd: true },
+    };
+
+    apply_vault_params(&mut vault, &params, 1_000);
+
+    assert_eq!(vault.base_fee, 25);
+    assert_eq!(vault.pyth_price_account, oracle);
+    assert_eq!(vault.max_age_price, 60);
+    assert_eq!(vault.min_stake_age, 600);
+    assert_eq!(vault.yield_drip_window, 3_600);
+    assert_eq!(vault.max_total_deposits, 1_000);
+    assert_eq!(vault.max_deposit_per_user, 100);
+    assert!(vault.caps_in_usd);
+}
+
+#[test]
+fn queued_change_fits_allocated_space() {
+    // queue_change allocates 114 bytes for the largest ParameterChange variant
+    let change = ParameterChange::Vault { vault_mint: Pubkey::new_unique(), params: VaultParams::default() };
+    assert_eq!(change.try_to_vec().unwrap().len(), 114);
+}
+
+#[test]
+fn multisig_config_requires_unique_signers_and_valid_threshold() {
+    let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    assert!(check_multisig_config(&signers, 2).is_ok());
+    assert!(check_multisig_config(&signers, 3).is_ok());
+    assert!(check_multisig_config(&signers, 0).is_err(), "zero threshold");
+    assert!(check_multisig_config(&signers, 4).is_err(), "threshold above signer count");
+    assert!(check_multisig_config(&[], 1).is_err(), "empty signer set");
+    assert!(check_multisig_config(&[signers[0], signers[0]], 1).is_err(), "duplicate signer");
+
+    let too_many: Vec<Pubkey> = (0..11).map(|_| Pubkey::new_unique()).collect();
+    assert!(check_multisig_config(&too_many, 6).is_err(), "more than MAX_MULTISIG_SIGNERS");
+}
+
+#[test]
+fn multisig_signer_lookup_and_approvals() {
+    let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let multisig = Multisig { signers: signers.clone(), threshold: 2, ..Default::default() };
+
+    assert_eq!(multisig_signer_index(&multisig, &signers[2]).unwrap(), 2);
+    assert!(multisig_signer_index(&multisig, &Pubkey::new_unique()).is_err());
+
+    assert_eq!(count_approvals(&[true, false, true]), 2);
+    assert_eq!(count_approvals(&[false, false, false]), 0);
+}
+
+#[test]
+fn multisig_executes_only_whitelisted_actions() {
+    assert!(is_whitelisted_action(instruction::UpdateVault::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::Collect::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::InitVault::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::UpdateTreasury::DISCRIMINATOR));
+
+    // Every admin-gated instruction stays reachable once the multisig is admin
+    assert!(is_whitelisted_action(instruction::GrantRole::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::SetStoptap::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::CloseVault::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::CancelRewardStream::DISCRIMINATOR));
+    assert!(is_whitelisted_action(instruction::CancelChange::DISCRIMINATOR));
+
+    // Proposals must not be able to recurse into the multisig or touch user funds
+    assert!(!is_whitelisted_action(instruction::ExecuteProposal::DISCRIMINATOR));
+    assert!(!is_whitelisted_action(instruction::Swap::DISCRIMINATOR));
+    assert!(!is_whitelisted_action(&[]));
+}
+
+#[test]
+fn fee_recipients_must_sum_to_full_weight() {
+    let dao = FeeRecipient { owner: Pubkey::new_unique(), weight_bps: 6_000 };
+    let insurance = FeeRecipient { owner: Pubkey::new_unique(), weight_bps: 4_000 };
+
+    assert!(check_fee_recipients(&[dao, insurance]).is_ok());
+    assert!(check_fee_recipients(&[dao]).is_err(), "weights below 10_000 bps");
+    assert!(check_fee_recipients(&[dao, insurance, insurance]).is_err(), "weights above 10_000 bps");
+    assert!(check_fee_recipients(&[]).is_err(), "no recipients");
+    assert!(check_fee_recipients(&[FeeRecipient { owner: Pubkey::default(), weight_bps: 10_000 }]).is_err(), "default owner");
+
+    let six: Vec<FeeRecipient> = (0..6).map(|i| FeeRecipient {
+        owner: Pubkey::new_unique(),
+        weight_bps: if i == 0 { 5_000 } else { 1_000 },
+    }).collect();
+    assert!(check_fee_recipients(&six).is_err(), "more than MAX_FEE_RECIPIENTS");
+}
+
+#[test]
+fn protocol_yield_split_distributes_everything() {
+    let recipients = [
+        FeeRecipient { owner: Pubkey::new_unique(), weight_bps: 3_333 },
+        FeeRecipient { owner: Pubkey::new_unique(), weight_bps: 3_333 },
+        FeeRecipient { owner: Pubkey::new_unique(), weight_bps: 3_334 },
+    ];
+
+    let shares = split_protocol_yield(1_000, &recipients).unwrap();
+    assert_eq!(shares, vec![333, 333, 334]);
+    assert_eq!(shares.iter().sum::<u64>(), 1_000);
+
+    // Rounding dust goes to the last recipient
+    let shares = split_protocol_yield(7, &recipients).unwrap();
+    assert_eq!(shares, vec![2, 2, 3]);
+
+    assert_eq!(split_protocol_yield(0, &recipients).unwrap(), vec![0, 0, 0]);
+}
+
+#[test]
+fn parameter_bounds_reject_out_of_range_values() {
+    let treasury = Treasury {
+        max_base_fee: 500,
+        max_protocol_fee_bps: 500,
+        max_oracle_age: 300,
+        min_deviation: 2,
+        ..Default::default()
+    };
+
+    assert!(check_vault_bounds(&treasury, 500, 300).is_ok());
+    assert!(check_vault_bounds(&treasury, 501, 300).is_err(), "base fee above max");
+    assert!(check_vault_bounds(&treasury, 500, 301).is_err(), "oracle age above max");
+
+    assert!(check_treasury_params(&treasury, 500, 2).is_ok());
+    assert!(check_treasury_params(&treasury, 10_001, 10).is_err(), "protocol fee above max");
+    assert!(check_treasury_params(&treasury, 5, 1).is_err(), "deviation below min");
+    assert!(check_treasury_params(&treasury, 5, 0).is_err(), "zero deviation");
+}
+
+#[test]
+fn parameter_bounds_must_be_usable() {
+    let bounds = ParameterBounds { max_base_fee: 500, max_protocol_fee_bps: 500, max_oracle_age: 300, min_deviation: 2 };
+    assert!(check_parameter_bounds(&bounds).is_ok());
+
+    assert!(check_parameter_bounds(&ParameterBounds { max_base_fee: 10_001, ..bounds }).is_err());
+    assert!(check_parameter_bounds(&ParameterBounds { max_protocol_fee_bps: 10_001, ..bounds }).is_err());
+    assert!(check_parameter_bounds(&ParameterBounds { max_oracle_age: 0, ..bounds }).is_err());
+    assert!(check_parameter_bounds(&ParameterBounds { min_deviation: 0, ..bounds }).is_err());
+}
+
+#[test]
+fn vault_status_gates_inflows_and_outflows() {
+    let active = Vault { status: VaultStatus::Active, ..Default::default() };
+    let swap_only_out = Vault { status: VaultStatus::SwapOnlyOut, ..Default::default() };
+    let withdraw_only = Vault { status: VaultStatus::WithdrawOnly, ..Default::default() };
+
+    assert!(check_vault_inflow(&active).is_ok());
+    assert!(check_vault_inflow(&swap_only_out).is_err());
+    assert!(check_vault_inflow(&withdraw_only).is_err());
+
+    assert!(check_vault_outflow(&active).is_ok());
+    assert!(check_vault_outflow(&swap_only_out).is_ok());
+    assert!(check_vault_outflow(&withdraw_only).is_err());
+
+    // The lifecycle only moves forward
+    assert!(VaultStatus::Active < VaultStatus::SwapOnlyOut);
+    assert!(VaultStatus::SwapOnlyOut < VaultStatus::WithdrawOnly);
+}
+
+#[test]
+fn stakers_are_counted_once_per_account() {
+    let mut vault = Vault::default();
+    let mut staker = Staker::default();
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    register_staker(&mut vault, &mut staker, owner, mint);
+    register_staker(&mut vault, &mut staker, owner, mint);
+    assert_eq!(vault.open_stakers, 1);
+    assert_eq!((staker.owner, staker.vault, staker.version), (owner, mint, STAKER_VERSION));
+}
+
+#[test]
+fn close_vault_requires_an_empty_retired_vault() {
+    let retired = Vault {status: VaultStatus::WithdrawOnly, unclaimed_yield: 3, ..Default::default()};
+    // Rounding residue of paid-out yield does not block closing
+    assert!(check_vault_closable(&retired, 0).is_ok());
+
+    assert!(check_vault_closable(&Vault::default(), 0).is_err(), "active vault");
+    assert!(check_vault_closable(&retired, 1).is_err(), "LP supply left");
+    assert!(check_vault_closable(&Vault {protocol_yield: 1, ..retired.clone()}, 0).is_err(), "uncollected protocol yield");
+    assert!(check_vault_closable(&Vault {undripped_yield: 1, ..retired.clone()}, 0).is_err(), "buffered fees");
+    assert!(check_vault_closable(&Vault {open_stakers: 1, ..retired.clone()}, 0).is_err(), "staker claims may be outstanding");
+}
+
+#[test]
+fn delisted_vaults_waive_unstake_fee() {
+    let drained = Vault { initial_liquidity: 1_000, current_liquidity: 100, ..Default::default() };
+    assert_eq!(unstake_fee_bps(&drained), 200);
+
+    assert_eq!(unstake_fee_bps(&Vault { status: VaultStatus::SwapOnlyOut, ..drained.clone() }), 0);
+    assert_eq!(unstake_fee_bps(&Vault { status: VaultStatus::WithdrawOnly, ..drained }), 0);
+}
+
+/// Serializes an account and drops the trailing 33 bytes from `version` onwards,
+/// reproducing the layout written before accounts were versioned.
+fn v0_bytes<T: AccountSerialize>(account: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.truncate(data.len() - 33);
+    data
+}
+
+#[test]
+fn v0_vault_upgrades_in_place() {
+    let pubkey = Pubkey::new_unique();
+    let old = Vault {base_fee: 30, initial_liquidity: 1_000, current_liquidity: 900, token_mint: pubkey, max_age_price: 60, protocol_yield: 7, ..Default::default()};
+    let data = v0_bytes(&old);
+
+    let vault = upgrade_vault(&data).unwrap();
+    assert_eq!(vault.version, VAULT_VERSION);
+    assert_eq!(vault.token_mint, pubkey);
+    assert_eq!((vault.base_fee, vault.current_liquidity, vault.protocol_yield), (30, 900, 7));
+    assert_eq!(vault.status, VaultStatus::Active);
+
+    // Upgrading twice is rejected
+    let mut current = Vec::new();
+    vault.try_serialize(&mut current).unwrap();
+    assert!(upgrade_vault(&current).is_err());
+}
+
+#[test]
+fn v0_staker_upgrades_in_place() {
+    let owner = Pubkey::new_unique();
+    let old = Staker {owner, pending_claim: 5, locked_amount: 100, ..Default::default()};
+
+    let staker = upgrade_staker(&v0_bytes(&old)).unwrap();
+    assert_eq!(staker.version, STAKER_VERSION);
+    assert_eq!((staker.owner, staker.pending_claim, staker.locked_amount), (owner, 5, 100));
+    assert_eq!(staker.delegate, Pubkey::default());
+
+    // Bytes of another account type are rejected
+    assert!(upgrade_staker(&v0_bytes(&Vault::default())).is_err());
+}
+
+#[test]
+fn v0_treasury_gets_default_bounds() {
+    let admin = Pubkey::new_unique();
+    let old = Treasury {admin, fee_bps: 10, deviation: 5, ..Default::default()};
+
+    // Written before parameter bounds were appended
+    let mut data = v0_bytes(&old);
+    data.truncate(data.len() - 4 * 8);
+
+    let treasury = upgrade_treasury(&data).unwrap();
+    assert_eq!(treasury.version, TREASURY_VERSION);
+    assert_eq!((treasury.admin, treasury.fee_bps, treasury.deviation), (admin, 10, 5));
+    assert_eq!(treasury.max_base_fee, DEFAULT_MAX_BASE_FEE);
+    assert_eq!(treasury.min_deviation, DEFAULT_MIN_DEVIATION);
+
+    // Bounds that were already configured are kept
+    let bounded = Treasury {max_base_fee: 42, ..old};
+    assert_eq!(upgrade_treasury(&v0_bytes(&bounded)).unwrap().max_base_fee, 42);
+
+    // Data longer than the current layout is not a known version
+    let mut oversized = vec![0u8; 8 + 400];
+    oversized[..8].copy_from_slice(Treasury::DISCRIMINATOR);
+    assert!(upgrade_treasury(&oversized).is_err());
+}
+
+#[test]
+fn claim_many_groups_must_be_whole() {
+    assert_eq!(claim_group_count(5).unwrap(), 1);
+    assert_eq!(claim_group_count(30).unwrap(), 6);
+    assert!(claim_group_count(0).is_err(), "at least one vault must be claimed");
+    assert!(claim_group_count(7).is_err(), "partial groups must be rejected");
+}
+
+#[test]
+fn claim_many_group_addresses_match_claim() {
+    let signer = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+    let lp_mint = Pubkey::new_unique();
+
+    let (vault, staker, lp_ata) = claim_group_addresses(&signer, &token_mint, &lp_mint);
+
+    let (expected_vault, _) = Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), token_mint.as_ref()], &oxedium_program::ID);
+    assert_eq!(vault, expected_vault);
+
+    let (expected_staker, _) = Pubkey::find_program_address(&[STAKER_SEED.as_bytes(), vault.as_ref(), signer.as_ref()], &oxedium_program::ID);
+    assert_eq!(staker, expected_staker);
+
+    // The LP account is the signer's associated token account, as in `claim`
+    assert_eq!(lp_ata, anchor_spl::associated_token::get_associated_token_address(&signer, &lp_mint));
+
+    // Another signer's position never matches
+    let (_, other_staker, other_lp_ata) = claim_group_addresses(&Pubkey::new_unique(), &token_mint, &lp_mint);
+    assert_ne!(staker, other_staker);
+    assert_ne!(lp_ata, other_lp_ata);
+}
+
+}
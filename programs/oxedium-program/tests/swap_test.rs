@@ -12,9 +12,9 @@ mod swap {
         let decimals_out = 6;
 
         let pubkey = Pubkey::default();
-        let vault_in = &Vault{base_fee: 1, initial_liquidity: 1000000000000, current_liquidity: 1000000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0};
-        let vault_out = &Vault{base_fee: 1, initial_liquidity: 150000000000, current_liquidity: 150000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0};
-        let treasury = &Treasury{stoptap: false, admin: pubkey, fee_bps: 1, deviation: 10};
+        let vault_in = &Vault{base_fee: 1, initial_liquidity: 1000000000000, current_liquidity: 1000000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+        let vault_out = &Vault{base_fee: 1, initial_liquidity: 150000000000, current_liquidity: 150000000000, token_mint: pubkey, pyth_price_account: pubkey, max_age_price: 300, lp_mint: pubkey, cumulative_yield_per_lp: 0, protocol_yield: 0, ..Default::default()};
+        let treasury = &Treasury{stoptap: false, admin: pubkey, fee_bps: 1, deviation: 10, ..Default::default()};
 
         let result = compute_swap_math(
             amount_in,
@@ -61,11 +61,12 @@ mod swap {
             lp_mint: pubkey,
             cumulative_yield_per_lp: 0,
             protocol_yield: 0,
+            ..Default::default()
         }
     }
 
     fn make_treasury(pubkey: Pubkey) -> Treasury {
-        Treasury { stoptap: false, admin: pubkey, fee_bps: 5, deviation: 10 }
+        Treasury { stoptap: false, admin: pubkey, fee_bps: 5, deviation: 10, ..Default::default() }
     }
 
     #[test]